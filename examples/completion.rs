@@ -0,0 +1,46 @@
+use dialoguer::{theme::ColorfulTheme, Completion, Input};
+
+struct MyCompletion {
+    options: Vec<String>,
+}
+
+impl Default for MyCompletion {
+    fn default() -> Self {
+        MyCompletion {
+            options: vec![
+                "orange".to_string(),
+                "apple".to_string(),
+                "banana".to_string(),
+            ],
+        }
+    }
+}
+
+impl Completion for MyCompletion {
+    /// Simple completion implementation based on substring
+    fn get(&self, input: &str) -> Option<String> {
+        let matches = self
+            .options
+            .iter()
+            .filter(|option| option.starts_with(input))
+            .collect::<Vec<_>>();
+
+        if matches.len() == 1 {
+            Some(matches[0].to_string())
+        } else {
+            None
+        }
+    }
+}
+
+fn main() {
+    let completion = MyCompletion::default();
+
+    let fruit: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Your favorite fruit")
+        .completion_with(&completion)
+        .interact_text()
+        .unwrap();
+
+    println!("Enjoy your {}!", fruit);
+}
@@ -0,0 +1,16 @@
+use dialoguer::{theme::ColorfulTheme, DatePicker};
+
+fn main() {
+    let date = DatePicker::with_theme(&ColorfulTheme::default())
+        .with_prompt("Pick a deadline")
+        .min_date(2021, 1, 1)
+        .max_date(2021, 12, 31)
+        .default(2021, 6, 15)
+        .interact_opt()
+        .unwrap();
+
+    match date {
+        Some((year, month, day)) => println!("Deadline set to {}-{:02}-{:02}", year, month, day),
+        None => println!("No deadline set"),
+    }
+}
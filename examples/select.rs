@@ -21,7 +21,7 @@ fn main() {
         .with_prompt("Optionally pick your flavor")
         .default(0)
         .items(&selections[..])
-        .interact_opt()
+        .interact_index_opt()
         .unwrap();
 
     if let Some(selection) = selection {
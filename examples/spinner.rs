@@ -0,0 +1,16 @@
+use std::{thread, time::Duration};
+
+use dialoguer::{theme::ColorfulTheme, Spinner};
+
+fn main() {
+    let spinner = Spinner::with_theme(&ColorfulTheme::default())
+        .message("Doing some heavy lifting")
+        .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+        .start()
+        .unwrap();
+
+    thread::sleep(Duration::from_secs(3));
+    spinner.stop().unwrap();
+
+    println!("All done!");
+}
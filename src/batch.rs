@@ -0,0 +1,295 @@
+//! Provides running several prompts in sequence
+
+use std::{
+    fmt::{Debug, Display},
+    io,
+    ops::{Deref, Index},
+    str::FromStr,
+};
+
+use crate::{Confirm, DatePicker, FuzzySelect, Input, MultiSelect, Password, Select, Sort};
+
+use console::Term;
+
+/// Trait for prompts that can be part of a [Batch](struct.Batch.html).
+pub trait BatchPrompt {
+    /// Runs the prompt on `term` and returns the answer.
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue>;
+}
+
+/// The answer to a prompt of a [Batch](struct.Batch.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchValue {
+    /// Text entered into an `Input` or `Password`.
+    Text(String),
+    /// Answer to a `Confirm`.
+    Bool(bool),
+    /// Index of the item picked in a `Select` or `FuzzySelect`.
+    Index(usize),
+    /// Indices returned by a `MultiSelect` or `Sort`.
+    Indices(Vec<usize>),
+    /// Date picked in a `DatePicker` as `(year, month, day)`.
+    Date(u16, u8, u8),
+}
+
+impl BatchValue {
+    /// Returns the text if this is a [Text](#variant.Text) answer.
+    pub fn as_text(&self) -> Option<&str> {
+        match *self {
+            BatchValue::Text(ref text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the answer if this is a [Bool](#variant.Bool) answer.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            BatchValue::Bool(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Returns the index if this is an [Index](#variant.Index) answer.
+    pub fn as_index(&self) -> Option<usize> {
+        match *self {
+            BatchValue::Index(idx) => Some(idx),
+            _ => None,
+        }
+    }
+
+    /// Returns the indices if this is an [Indices](#variant.Indices) answer.
+    pub fn as_indices(&self) -> Option<&[usize]> {
+        match *self {
+            BatchValue::Indices(ref indices) => Some(indices),
+            _ => None,
+        }
+    }
+
+    /// Returns the date if this is a [Date](#variant.Date) answer.
+    pub fn as_date(&self) -> Option<(u16, u8, u8)> {
+        match *self {
+            BatchValue::Date(year, month, day) => Some((year, month, day)),
+            _ => None,
+        }
+    }
+}
+
+/// The named answer to a prompt of a [Batch](struct.Batch.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchResult {
+    /// The name the prompt was added with.
+    pub name: String,
+    /// The answer to the prompt.
+    pub value: BatchValue,
+}
+
+/// The answers to all prompts of a [Batch](struct.Batch.html), in order.
+///
+/// Besides iterating the results they can be looked up by name, indexing
+/// with a name that was not added panics.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchResults(Vec<BatchResult>);
+
+impl BatchResults {
+    /// Returns the answer to the prompt added with `name`.
+    pub fn get(&self, name: &str) -> Option<&BatchValue> {
+        self.0
+            .iter()
+            .find(|result| result.name == name)
+            .map(|result| &result.value)
+    }
+
+    /// Returns the results as a vector.
+    pub fn into_vec(self) -> Vec<BatchResult> {
+        self.0
+    }
+}
+
+impl Deref for BatchResults {
+    type Target = [BatchResult];
+
+    fn deref(&self) -> &[BatchResult] {
+        &self.0
+    }
+}
+
+impl<'b> Index<&'b str> for BatchResults {
+    type Output = BatchValue;
+
+    fn index(&self, name: &'b str) -> &BatchValue {
+        self.get(name)
+            .unwrap_or_else(|| panic!("no prompt named `{}` in batch", name))
+    }
+}
+
+/// Runs several prompts in sequence and collects their answers.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{Batch, Confirm, Input};
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut name = Input::<String>::new();
+///     name.with_prompt("Your name");
+///
+///     let mut confirmed = Confirm::new();
+///     confirmed.with_prompt("Is that right?");
+///
+///     let results = Batch::new()
+///         .add("name", &name)
+///         .add("confirmed", &confirmed)
+///         .run()?;
+///
+///     if results["confirmed"].as_bool() == Some(true) {
+///         println!("Hello {}!", results["name"].as_text().unwrap());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct Batch<'a> {
+    prompts: Vec<(String, Box<dyn BatchPrompt + 'a>)>,
+}
+
+impl<'a> Batch<'a> {
+    /// Creates an empty batch.
+    pub fn new() -> Batch<'a> {
+        Batch::default()
+    }
+
+    /// Adds a prompt whose answer is stored under `name`.
+    ///
+    /// Prompts can be added by value or by reference.
+    pub fn add<S: Into<String>, P: BatchPrompt + 'a>(
+        &mut self,
+        name: S,
+        prompt: P,
+    ) -> &mut Batch<'a> {
+        self.prompts.push((name.into(), Box::new(prompt)));
+        self
+    }
+
+    /// Runs all prompts in the order they were added.
+    ///
+    /// The dialogs are rendered on stderr. The first failing prompt stops
+    /// the batch and its error is returned.
+    pub fn run(&self) -> io::Result<BatchResults> {
+        self.run_on(&Term::stderr())
+    }
+
+    /// Like [run](#method.run) but allows a specific terminal to be set.
+    pub fn run_on(&self, term: &Term) -> io::Result<BatchResults> {
+        let mut results = Vec::with_capacity(self.prompts.len());
+
+        for (name, prompt) in &self.prompts {
+            results.push(BatchResult {
+                name: name.clone(),
+                value: prompt.run_on(term)?,
+            });
+        }
+
+        Ok(BatchResults(results))
+    }
+}
+
+impl<P: BatchPrompt + ?Sized> BatchPrompt for &P {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        (**self).run_on(term)
+    }
+}
+
+impl<P: BatchPrompt + ?Sized> BatchPrompt for &mut P {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        (**self).run_on(term)
+    }
+}
+
+impl<'a, T> BatchPrompt for Input<'a, T>
+where
+    T: Clone + FromStr + Display,
+    T::Err: Display + Debug,
+{
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        Ok(BatchValue::Text(self.interact_text_on(term)?.to_string()))
+    }
+}
+
+impl<'a> BatchPrompt for Password<'a> {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        Ok(BatchValue::Text(self.interact_on(term)?))
+    }
+}
+
+impl<'a> BatchPrompt for Confirm<'a> {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        Ok(BatchValue::Bool(self.interact_on(term)?))
+    }
+}
+
+impl<'a> BatchPrompt for Select<'a> {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        Ok(BatchValue::Index(self.interact_on(term)?))
+    }
+}
+
+impl<'a> BatchPrompt for FuzzySelect<'a> {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        Ok(BatchValue::Index(self.interact_on(term)?))
+    }
+}
+
+impl<'a, V> BatchPrompt for MultiSelect<'a, V> {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        Ok(BatchValue::Indices(self.interact_on(term)?))
+    }
+}
+
+impl<'a> BatchPrompt for Sort<'a> {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        Ok(BatchValue::Indices(self.interact_on(term)?))
+    }
+}
+
+impl<'a> BatchPrompt for DatePicker<'a> {
+    fn run_on(&self, term: &Term) -> io::Result<BatchValue> {
+        let (year, month, day) = self.interact_on(term)?;
+        Ok(BatchValue::Date(year, month, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> BatchResults {
+        BatchResults(vec![
+            BatchResult {
+                name: "name".into(),
+                value: BatchValue::Text("Ferris".into()),
+            },
+            BatchResult {
+                name: "confirmed".into(),
+                value: BatchValue::Bool(true),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_results_by_name() {
+        let results = results();
+
+        assert_eq!(results["name"].as_text(), Some("Ferris"));
+        assert_eq!(results["confirmed"].as_bool(), Some(true));
+        assert_eq!(results["confirmed"].as_text(), None);
+        assert!(results.get("missing").is_none());
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_results_missing_name() {
+        let _ = &results()["missing"];
+    }
+}
@@ -0,0 +1,12 @@
+//! Provides completion for text inputs
+
+/// Trait for input completion.
+///
+/// Implementors that want to cycle through several candidates on repeated
+/// presses of Tab can keep track of their state with interior mutability.
+pub trait Completion {
+    /// Invoked with the current input when the user presses Tab.
+    ///
+    /// Returns the completed input, or `None` if there is nothing to complete.
+    fn get(&self, input: &str) -> Option<String>;
+}
@@ -27,6 +27,7 @@ pub struct Editor {
     extension: String,
     require_save: bool,
     trim_newlines: bool,
+    allow_empty: bool,
 }
 
 fn get_default_editor() -> OsString {
@@ -57,6 +58,7 @@ impl Editor {
             extension: ".txt".into(),
             require_save: true,
             trim_newlines: true,
+            allow_empty: true,
         }
     }
 
@@ -86,9 +88,19 @@ impl Editor {
         self
     }
 
+    /// Enables or disables accepting an empty text.
+    ///
+    /// When disabled an empty text is treated like an unsaved file and
+    /// [edit](#method.edit) returns `None`. This is on by default.
+    pub fn allow_empty(&mut self, val: bool) -> &mut Editor {
+        self.allow_empty = val;
+        self
+    }
+
     /// Launches the editor to edit a string.
     ///
-    /// Returns `None` if the file was not saved or otherwise the
+    /// Returns `None` if the file was not saved (or is empty when
+    /// [allow_empty](#method.allow_empty) is disabled) or otherwise the
     /// entered text.
     pub fn edit(&self, s: &str) -> io::Result<Option<String>> {
         let mut f = tempfile::Builder::new()
@@ -124,6 +136,10 @@ impl Editor {
             rv.truncate(len);
         }
 
+        if !self.allow_empty && rv.trim().is_empty() {
+            return Ok(None);
+        }
+
         Ok(Some(rv))
     }
 }
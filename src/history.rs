@@ -0,0 +1,15 @@
+//! Provides history for text inputs
+
+/// Trait for input history.
+///
+/// Entries are addressed by their distance from the most recent one, so
+/// position `0` is the value entered last.
+pub trait History {
+    /// Invoked when the user browses the history with the arrow keys.
+    ///
+    /// Returns `None` if there is no entry at `pos`.
+    fn read(&self, pos: usize) -> Option<String>;
+
+    /// Invoked with the value the user entered once the input is accepted.
+    fn write(&mut self, val: &str);
+}
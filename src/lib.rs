@@ -13,19 +13,106 @@
 //! * Confirmation prompts
 //! * Input prompts (regular and password)
 //! * Input validation
+//! * Password strength meters
+//! * Input completion
+//! * Input history
 //! * Selections prompts (single and multi)
+//! * Selection prompts with a search bar (fuzzy or by substring)
+//! * Table selection prompts
+//! * Date pickers
+//! * Key press prompts
 //! * Other kind of prompts
+//! * Spinners for long-running operations
+//! * Progress bars
+//! * Running several prompts in a batch
 //! * Editor launching
+//!
+//! # Terminals
+//!
+//! Prompts render on stderr unless a terminal is passed to one of the
+//! `*_on` methods. The `console` crate is re-exported as `dialoguer::console`,
+//! so `Term`, `Key` and `Style` can be used without depending on it:
+//!
+//! ```rust,no_run
+//! use dialoguer::{console::Term, Confirm};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let proceed = Confirm::new().interact_on(&Term::stdout())?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Prompts adapt to a resized terminal the next time they render, which is
+//! after the next key press. Paged prompts that size their pages to the
+//! terminal recompute the page size then.
+//!
+//! The crate needs the standard library. Prompts read keys and render
+//! through `console::Term` and report failures as `std::io::Error`, so they
+//! are not available on `no_std` targets.
+//!
+//! # Cancelling Prompts
+//!
+//! Prompts that can be cancelled with 'Esc' offer variants returning an
+//! `Option`, which is `None` if the user cancelled:
+//!
+//! | Prompt             | Cancellable variants                                         |
+//! |--------------------|--------------------------------------------------------------|
+//! | `Confirm`          | `interact_opt`, `interact_on_opt`                            |
+//! | `DatePicker`       | `interact_opt`, `interact_on_opt`                            |
+//! | `FuzzySelect`      | `interact_opt`, `interact_on_opt`                            |
+//! | `Input`            | `interact_text_opt`, `interact_text_on_opt`                  |
+//! | `MultiSelect`      | `interact_opt`, `interact_on_opt`                            |
+//! | `Password`         | `interact_opt`, `interact_on_opt`                            |
+//! | `Select`           | `interact_index_opt`, `interact_on_opt`, `interact_text_opt` |
+//! | `SelectWithSearch` | `interact_opt`, `interact_on_opt`                            |
+//! | `Sort`             | `interact_opt`, `interact_on_opt`                            |
+//! | `TableSelect`      | `interact_opt`, `interact_on_opt`                            |
+//!
+//! `Input` can only be cancelled while reading key by key, as its `interact`
+//! leaves reading the line to the terminal. `Password` can only be cancelled
+//! on a terminal, elsewhere it reads the password as a line. The
+//! non-cancellable variants treat 'Esc' differently per prompt,
+//! `MultiSelect` for instance restores its defaults.
+//!
+//! Pressing Ctrl+C in a prompt raises SIGINT, which ends the program unless it
+//! handles the signal. If it does, the prompt restores the cursor and returns
+//! an error of kind `std::io::ErrorKind::Interrupted`.
+//!
+//! # Reusing Prompts
+//!
+//! All prompts implement `Clone`, so a configured prompt can serve as a
+//! template for several interactions. Clones share references like the
+//! theme, they are not deep copies of them.
 
+pub use batch::{Batch, BatchPrompt, BatchResult, BatchResults, BatchValue};
+pub use completion::Completion;
 pub use console;
 pub use edit::Editor;
+pub use history::History;
 pub use prompts::{
-    confirm::Confirm, input::Input, multi_select::MultiSelect, password::Password, select::Select,
+    checkbox::Checkbox,
+    confirm::Confirm,
+    date_picker::DatePicker,
+    fuzzy_select::FuzzySelect,
+    input::Input,
+    key_press::KeyPress,
+    multi_select::{MultiSelect, PagedSelections},
+    password::Password,
+    progress::ProgressBar,
+    select::Select,
+    select_with_search::SelectWithSearch,
     sort::Sort,
+    spinner::{Spinner, SpinnerHandle},
+    table_select::TableSelect,
 };
+pub use strength::StrengthMeter;
 pub use validate::Validator;
 
+mod batch;
+mod completion;
 mod edit;
+mod history;
 mod prompts;
+mod strength;
 pub mod theme;
 mod validate;
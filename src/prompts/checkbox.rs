@@ -0,0 +1,20 @@
+use crate::prompts::multi_select::MultiSelect;
+
+/// Renders a list of checkboxes.
+///
+/// This is another name for [MultiSelect](struct.MultiSelect.html), all of its
+/// builder and interaction methods are available, including items carrying
+/// values of type `V`.
+///
+/// ## Example usage
+/// ```rust,no_run
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// use dialoguer::Checkbox;
+///
+/// let chosen : Vec<usize> = Checkbox::new()
+///     .items(&["Option 1", "Option 2"])
+///     .interact()?;
+/// # Ok(())
+/// # }
+/// ```
+pub type Checkbox<'a, V = ()> = MultiSelect<'a, V>;
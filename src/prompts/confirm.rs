@@ -1,8 +1,11 @@
 use std::io;
 
-use crate::theme::{SimpleTheme, TermThemeRenderer, Theme};
+use crate::{
+    prompts::{env, keys::read_key_with_io},
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
 
-use console::Term;
+use console::{Key, Term};
 
 /// Renders a confirm prompt.
 ///
@@ -19,11 +22,16 @@ use console::Term;
 /// }
 /// # Ok(()) } fn main() { test().unwrap(); }
 /// ```
+#[derive(Clone)]
 pub struct Confirm<'a> {
     prompt: String,
     default: Option<bool>,
     show_default: bool,
     wait_for_newline: bool,
+    accept_any_key: bool,
+    yes_text: String,
+    no_text: String,
+    env_var: Option<String>,
     theme: &'a dyn Theme,
 }
 
@@ -36,7 +44,7 @@ impl<'a> Default for Confirm<'a> {
 impl<'a> Confirm<'a> {
     /// Creates a confirm prompt.
     pub fn new() -> Confirm<'static> {
-        Confirm::with_theme(&SimpleTheme)
+        Confirm::with_theme(default_theme())
     }
 
     /// Creates a confirm prompt with a specific theme.
@@ -61,6 +69,10 @@ impl<'a> Confirm<'a> {
             default: None,
             show_default: true,
             wait_for_newline: false,
+            accept_any_key: false,
+            yes_text: "yes".into(),
+            no_text: "no".into(),
+            env_var: None,
             theme,
         }
     }
@@ -91,6 +103,65 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Confirms on any key, e.g. for "press any key to continue" prompts.
+    ///
+    /// When `true`, typing the first character of the negative answer or
+    /// hitting Escape answers `false` and every other key answers `true`. If
+    /// a default is set, Enter still accepts it. Escape cancels the prompt
+    /// instead when using [interact_opt](#method.interact_opt).
+    ///
+    /// Only applies when not [waiting for a newline](#method.wait_for_newline).
+    /// The default is `false`.
+    pub fn accept_any_key(&mut self, val: bool) -> &mut Confirm<'a> {
+        self.accept_any_key = val;
+        self
+    }
+
+    /// Sets the text of the affirmative answer.
+    ///
+    /// The user confirms by typing the first character of the text. The
+    /// default is `yes`.
+    ///
+    /// ## Examples
+    /// ```rust,no_run
+    /// use dialoguer::Confirm;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let proceed = Confirm::new()
+    ///     .with_prompt("Fortfahren?")
+    ///     .yes_text("ja")
+    ///     .no_text("nein")
+    ///     .interact()?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn yes_text<S: Into<String>>(&mut self, text: S) -> &mut Confirm<'a> {
+        self.yes_text = text.into();
+        self
+    }
+
+    /// Sets the text of the negative answer.
+    ///
+    /// The user declines by typing the first character of the text. The
+    /// default is `no`.
+    pub fn no_text<S: Into<String>>(&mut self, text: S) -> &mut Confirm<'a> {
+        self.no_text = text.into();
+        self
+    }
+
+    /// Reads the answer from the environment variable `name` instead of the terminal.
+    ///
+    /// When the variable is set to a non-empty value the prompt is not shown.
+    /// The value is either one of the answer texts or the key the user would
+    /// press, both are case-insensitive. An answer that is not valid results
+    /// in an error of kind `InvalidInput`.
+    ///
+    /// This allows running the prompt non-interactively, e.g. in CI.
+    pub fn env_var<S: Into<String>>(&mut self, name: S) -> &mut Confirm<'a> {
+        self.env_var = Some(name.into());
+        self
+    }
+
     /// Sets a default.
     ///
     /// Out of the box the prompt does not have a default and will continue
@@ -101,6 +172,16 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Pre-selects yes (`true`) or no (`false`).
+    ///
+    /// The pre-selected option is highlighted in the prompt with the
+    /// `confirm_prompt_default_selection` style of the theme and pressing
+    /// Enter without typing anything else selects it. This is the same as
+    /// [default](#method.default).
+    pub fn default_option(&mut self, val: bool) -> &mut Confirm<'a> {
+        self.default(val)
+    }
+
     /// Disables or enables the default value display.
     ///
     /// The default is to append `[y/n]` to the prompt to tell the
@@ -121,13 +202,22 @@ impl<'a> Confirm<'a> {
         self.interact_on(&Term::stderr())
     }
 
+    /// Enables user interaction and returns the result.
+    ///
+    /// This method is similar to [interact_on_opt](#method.interact_on_opt) except for the fact that it does not allow selection of the terminal.
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(bool)` if user answered or `None` if user cancelled with 'Esc'.
+    pub fn interact_opt(&self) -> io::Result<Option<bool>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
     /// Like [interact](#method.interact) but allows a specific terminal to be set.
     ///
     /// ## Examples
     ///
     /// ```rust,no_run
     /// use dialoguer::Confirm;
-    /// use console::Term;
+    /// use dialoguer::console::Term;
     ///
     /// # fn main() -> std::io::Result<()> {
     /// let proceed = Confirm::new()
@@ -137,7 +227,65 @@ impl<'a> Confirm<'a> {
     /// # }
     /// ```
     pub fn interact_on(&self, term: &Term) -> io::Result<bool> {
-        let mut render = TermThemeRenderer::new(term, self.theme);
+        self.interact_on_with_io(term, term)
+    }
+
+    /// Like [interact_on](#method.interact_on) but reads keys from `input` and renders on `output`.
+    pub fn interact_on_with_io(&self, input: &Term, output: &Term) -> io::Result<bool> {
+        self._interact_on(input, output, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Confirm;
+    /// use dialoguer::console::Term;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let proceed = Confirm::new()
+    ///     .with_prompt("Do you wish to continue?")
+    ///     .interact_on_opt(&Term::stderr())?;
+    ///
+    /// match proceed {
+    ///     Some(true) => println!("User wants to continue"),
+    ///     Some(false) => println!("User does not want to continue"),
+    ///     None => println!("User did not answer"),
+    /// }
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<bool>> {
+        self._interact_on(term, term, true)
+    }
+
+    /// Like [interact_on_opt](#method.interact_on_opt) but reads keys from `input` and renders on `output`.
+    #[inline]
+    pub fn interact_on_opt_with_io(&self, input: &Term, output: &Term) -> io::Result<Option<bool>> {
+        self._interact_on(input, output, true)
+    }
+
+    fn _interact_on(
+        &self,
+        input: &Term,
+        output: &Term,
+        allow_quit: bool,
+    ) -> io::Result<Option<bool>> {
+        if let Some(ref var) = self.env_var {
+            if let Some(value) = env::answer(Some(var)) {
+                return self.text_answer(&value).map(Some).ok_or_else(|| {
+                    env::invalid_answer(
+                        var,
+                        &format!("expected `{}` or `{}`", self.yes_text, self.no_text),
+                    )
+                });
+            }
+        }
+
+        let mut render = TermThemeRenderer::new(output, self.theme);
 
         let default_if_show = if self.show_default {
             self.default
@@ -145,10 +293,15 @@ impl<'a> Confirm<'a> {
             None
         };
 
-        render.confirm_prompt(&self.prompt, default_if_show)?;
+        render.confirm_prompt_with_labels(
+            &self.prompt,
+            default_if_show,
+            &self.yes_text,
+            &self.no_text,
+        )?;
 
-        term.hide_cursor()?;
-        term.flush()?;
+        output.hide_cursor()?;
+        output.flush()?;
 
         let rv;
 
@@ -158,16 +311,11 @@ impl<'a> Confirm<'a> {
             let mut value = default_if_show;
 
             loop {
-                let input = term.read_char()?;
-
-                match input {
-                    'y' | 'Y' => {
-                        value = Some(true);
-                    }
-                    'n' | 'N' => {
-                        value = Some(false);
+                match read_key_with_io(input, output)? {
+                    Key::Char(chr) if self.answer(chr).is_some() => {
+                        value = self.answer(chr);
                     }
-                    '\n' | '\r' => {
+                    Key::Enter => {
                         value = value.or(self.default);
 
                         if let Some(val) = value {
@@ -177,23 +325,46 @@ impl<'a> Confirm<'a> {
                             continue;
                         }
                     }
+                    Key::Escape if allow_quit => {
+                        return self.quit(output);
+                    }
+                    Key::Unknown => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::NotConnected,
+                            "Not a terminal",
+                        ))
+                    }
                     _ => {
                         continue;
                     }
                 };
 
-                term.clear_line()?;
-                render.confirm_prompt(&self.prompt, value)?;
+                output.clear_line()?;
+                render.confirm_prompt_with_labels(
+                    &self.prompt,
+                    value,
+                    &self.yes_text,
+                    &self.no_text,
+                )?;
             }
         } else {
             // Default behavior: matches continuously on every keystroke,
             // and does not wait for user to hit the Enter key.
             loop {
-                let input = term.read_char()?;
-                let value = match input {
-                    'y' | 'Y' => true,
-                    'n' | 'N' => false,
-                    '\n' | '\r' if self.default.is_some() => self.default.unwrap(),
+                let value = match read_key_with_io(input, output)? {
+                    Key::Char(chr) if self.answer(chr).is_some() => self.answer(chr).unwrap(),
+                    Key::Enter if self.default.is_some() => self.default.unwrap(),
+                    Key::Escape if allow_quit => {
+                        return self.quit(output);
+                    }
+                    Key::Unknown => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::NotConnected,
+                            "Not a terminal",
+                        ))
+                    }
+                    Key::Escape if self.accept_any_key => false,
+                    _ if self.accept_any_key => true,
                     _ => {
                         continue;
                     }
@@ -204,11 +375,100 @@ impl<'a> Confirm<'a> {
             }
         }
 
-        term.clear_line()?;
-        render.confirm_prompt_selection(&self.prompt, rv)?;
-        term.show_cursor()?;
-        term.flush()?;
+        output.clear_line()?;
+        render.confirm_prompt_selection_with_labels(
+            &self.prompt,
+            rv,
+            &self.yes_text,
+            &self.no_text,
+        )?;
+        output.show_cursor()?;
+        output.flush()?;
+
+        Ok(Some(rv))
+    }
+
+    /// Returns the answer `chr` stands for, matching the first character of
+    /// the answer texts case-insensitively.
+    fn answer(&self, chr: char) -> Option<bool> {
+        let matches = |text: &str| {
+            text.chars()
+                .take(1)
+                .any(|first| first.to_lowercase().eq(chr.to_lowercase()))
+        };
+
+        if matches(&self.yes_text) {
+            Some(true)
+        } else if matches(&self.no_text) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the answer `text` stands for, either a whole answer text or a
+    /// single key.
+    fn text_answer(&self, text: &str) -> Option<bool> {
+        let text = text.trim().to_lowercase();
+        let mut chars = text.chars();
+
+        if text == self.yes_text.to_lowercase() {
+            Some(true)
+        } else if text == self.no_text.to_lowercase() {
+            Some(false)
+        } else {
+            match (chars.next(), chars.next()) {
+                (Some(chr), None) => self.answer(chr),
+                _ => None,
+            }
+        }
+    }
+
+    /// Clears the prompt after the user cancelled it.
+    fn quit(&self, output: &Term) -> io::Result<Option<bool>> {
+        output.clear_line()?;
+        output.show_cursor()?;
+        output.flush()?;
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_custom_texts() {
+        let mut confirm = Confirm::new();
+
+        assert_eq!(confirm.answer('y'), Some(true));
+        assert_eq!(confirm.answer('N'), Some(false));
+
+        confirm.yes_text("ja").no_text("nein");
+
+        assert_eq!(confirm.answer('J'), Some(true));
+        assert_eq!(confirm.answer('n'), Some(false));
+        assert_eq!(confirm.answer('y'), None);
+    }
+
+    #[test]
+    fn test_env_var() {
+        std::env::set_var("DIALOGUER_TEST_CONFIRM", "Nein");
+        std::env::set_var("DIALOGUER_TEST_CONFIRM_INVALID", "maybe");
+
+        let mut confirm = Confirm::new();
+        confirm.yes_text("ja").no_text("nein");
+
+        let term = Term::stderr();
+
+        confirm.env_var("DIALOGUER_TEST_CONFIRM");
+        assert_eq!(confirm.interact_on(&term).unwrap(), false);
 
-        return Ok(rv);
+        confirm.env_var("DIALOGUER_TEST_CONFIRM_INVALID");
+        assert_eq!(
+            confirm.interact_on(&term).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
     }
 }
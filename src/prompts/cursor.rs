@@ -0,0 +1,26 @@
+use console::Term;
+
+/// Shows the cursor of a terminal again when dropped.
+///
+/// Prompts hide the cursor while rendering. Holding the guard for the whole
+/// interaction restores the cursor even if the prompt returns early with an
+/// error or panics.
+pub struct CursorGuard<'a> {
+    term: &'a Term,
+}
+
+impl<'a> CursorGuard<'a> {
+    /// Creates a guard for the cursor of `term`.
+    pub fn new(term: &'a Term) -> CursorGuard<'a> {
+        CursorGuard { term }
+    }
+}
+
+impl<'a> Drop for CursorGuard<'a> {
+    fn drop(&mut self) {
+        if self.term.features().is_attended() {
+            let _ = self.term.show_cursor();
+            let _ = self.term.flush();
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use std::{
+    io,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    prompts::keys::read_key,
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
+
+use console::{Key, Term};
+
+/// Renders a date picker showing a calendar of the selected month.
+///
+/// The arrow keys move the selected day, PageUp and PageDown switch to the
+/// previous and next month. Dates are `(year, month, day)` tuples, with
+/// months and days counted from 1.
+///
+/// With the `chrono` feature enabled dates can also be returned as
+/// `chrono::NaiveDate`.
+///
+/// ## Example usage
+///
+/// ```rust,no_run
+/// use dialoguer::DatePicker;
+///
+/// fn main() -> std::io::Result<()> {
+///     let (year, month, day) = DatePicker::new()
+///         .with_prompt("Deadline")
+///         .min_date(2021, 1, 1)
+///         .interact()?;
+///
+///     println!("Due on {}-{:02}-{:02}", year, month, day);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DatePicker<'a> {
+    prompt: String,
+    default: Option<Date>,
+    min: Date,
+    max: Date,
+    clear: bool,
+    theme: &'a dyn Theme,
+}
+
+impl<'a> Default for DatePicker<'a> {
+    fn default() -> DatePicker<'a> {
+        DatePicker::new()
+    }
+}
+
+impl<'a> DatePicker<'a> {
+    /// Creates a date picker.
+    pub fn new() -> DatePicker<'static> {
+        DatePicker::with_theme(default_theme())
+    }
+
+    /// Creates a date picker with a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme) -> DatePicker<'a> {
+        DatePicker {
+            prompt: "".into(),
+            default: None,
+            min: Date::new(1, 1, 1),
+            max: Date::new(9999, 12, 31),
+            clear: true,
+            theme,
+        }
+    }
+
+    /// Sets the date picker prompt.
+    pub fn with_prompt<S: Into<String>>(&mut self, prompt: S) -> &mut DatePicker<'a> {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the date selected initially.
+    ///
+    /// The default is today.
+    pub fn default(&mut self, year: u16, month: u8, day: u8) -> &mut DatePicker<'a> {
+        self.default = Some(Date::new(year, month, day));
+        self
+    }
+
+    /// Sets the earliest date that can be selected.
+    pub fn min_date(&mut self, year: u16, month: u8, day: u8) -> &mut DatePicker<'a> {
+        self.min = Date::new(year, month, day);
+        self
+    }
+
+    /// Sets the latest date that can be selected.
+    pub fn max_date(&mut self, year: u16, month: u8, day: u8) -> &mut DatePicker<'a> {
+        self.max = Date::new(year, month, day);
+        self
+    }
+
+    /// Indicates whether the calendar should be erased from the screen after interaction.
+    ///
+    /// The default is to clear the calendar.
+    pub fn clear(&mut self, val: bool) -> &mut DatePicker<'a> {
+        self.clear = val;
+        self
+    }
+
+    /// Enables user interaction and returns the selected date.
+    ///
+    /// The dialog is rendered on stderr.
+    pub fn interact(&self) -> io::Result<(u16, u8, u8)> {
+        self.interact_on(&Term::stderr())
+    }
+
+    /// Enables user interaction and returns the selected date.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(date)` if the user confirmed a date or `None` if the user cancelled with 'Esc'.
+    pub fn interact_opt(&self) -> io::Result<Option<(u16, u8, u8)>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
+    /// Like [interact](#method.interact) but allows a specific terminal to be set.
+    pub fn interact_on(&self, term: &Term) -> io::Result<(u16, u8, u8)> {
+        self._interact_on(term, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<(u16, u8, u8)>> {
+        self._interact_on(term, true)
+    }
+
+    /// Like [interact](#method.interact) but returns a `chrono::NaiveDate`.
+    #[cfg(feature = "chrono")]
+    pub fn interact_date(&self) -> io::Result<chrono::NaiveDate> {
+        self.interact_date_on(&Term::stderr())
+    }
+
+    /// Like [interact_on](#method.interact_on) but returns a `chrono::NaiveDate`.
+    #[cfg(feature = "chrono")]
+    pub fn interact_date_on(&self, term: &Term) -> io::Result<chrono::NaiveDate> {
+        let (year, month, day) = self.interact_on(term)?;
+
+        chrono::NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Invalid date selected"))
+    }
+
+    fn _interact_on(&self, term: &Term, allow_quit: bool) -> io::Result<Option<(u16, u8, u8)>> {
+        for date in [self.min, self.max].iter().chain(self.default.iter()) {
+            if !date.is_valid() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Invalid date {}-{:02}-{:02} given to `DatePicker`",
+                        date.year, date.month, date.day
+                    ),
+                ));
+            }
+        }
+
+        if self.min > self.max {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Minimum date given to `DatePicker` is after the maximum date",
+            ));
+        }
+
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut sel = self.clamp(self.default.unwrap_or_else(Date::today));
+
+        loop {
+            render.select_prompt(&self.prompt)?;
+            render.date_picker_month(sel.year, sel.month)?;
+
+            let offset = Date::new(sel.year, sel.month, 1).weekday();
+            let days: Vec<_> = (0..offset)
+                .map(|_| None)
+                .chain((1..=sel.days_in_month()).map(|day| {
+                    let date = Date::new(sel.year, sel.month, day);
+                    Some((day, day == sel.day, self.min <= date && date <= self.max))
+                }))
+                .collect();
+
+            for week in days.chunks(7) {
+                render.date_picker_week(week)?;
+            }
+
+            term.hide_cursor()?;
+            term.flush()?;
+
+            let next = match read_key(term)? {
+                Key::ArrowLeft | Key::Char('h') => sel.add_days(-1),
+                Key::ArrowRight | Key::Char('l') => sel.add_days(1),
+                Key::ArrowUp | Key::Char('k') => sel.add_days(-7),
+                Key::ArrowDown | Key::Char('j') => sel.add_days(7),
+                Key::PageUp => sel.add_months(-1),
+                Key::PageDown => sel.add_months(1),
+                Key::Escape if allow_quit => {
+                    if self.clear {
+                        render.clear()?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
+                }
+                Key::Enter => {
+                    if self.clear {
+                        render.clear()?;
+                    }
+
+                    render.select_prompt_selection(
+                        &self.prompt,
+                        &format!("{}-{:02}-{:02}", sel.year, sel.month, sel.day),
+                    )?;
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(Some((sel.year, sel.month, sel.day)));
+                }
+                _ => sel,
+            };
+
+            sel = self.clamp(next);
+            render.clear()?;
+        }
+    }
+
+    /// Moves `date` into the allowed range.
+    fn clamp(&self, date: Date) -> Date {
+        if date < self.min {
+            self.min
+        } else if date > self.max {
+            self.max
+        } else {
+            date
+        }
+    }
+}
+
+/// A date of the proleptic Gregorian calendar.
+///
+/// The field order makes the derived ordering chronological.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    fn new(year: u16, month: u8, day: u8) -> Date {
+        Date { year, month, day }
+    }
+
+    /// Returns the current date in UTC.
+    fn today() -> Date {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        Date::from_days((secs / 86400) as i64)
+    }
+
+    fn is_valid(self) -> bool {
+        self.year >= 1
+            && self.month >= 1
+            && self.month <= 12
+            && self.day >= 1
+            && self.day <= self.days_in_month()
+    }
+
+    // `is_multiple_of` is too recent for the supported compilers
+    #[allow(clippy::manual_is_multiple_of)]
+    fn is_leap_year(self) -> bool {
+        self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0)
+    }
+
+    fn days_in_month(self) -> u8 {
+        match self.month {
+            2 if self.is_leap_year() => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the day of the week, counted from 0 for monday.
+    fn weekday(self) -> usize {
+        // 1970-01-01 was a thursday
+        (self.to_days() + 3).rem_euclid(7) as usize
+    }
+
+    fn add_days(self, days: i64) -> Date {
+        Date::from_days(self.to_days() + days)
+    }
+
+    /// Moves by `months`, keeping the day within the target month.
+    fn add_months(self, months: i64) -> Date {
+        let total = self.year as i64 * 12 + (self.month as i64 - 1) + months;
+        let mut date = Date::new(
+            total.div_euclid(12).max(0).min(u16::MAX as i64) as u16,
+            (total.rem_euclid(12) + 1) as u8,
+            1,
+        );
+        date.day = self.day.min(date.days_in_month());
+        date
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let (month, day) = (self.month as i64, self.day as i64);
+        let year = self.year as i64 - if month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Inverse of [to_days](#method.to_days), saturating outside of the
+    /// supported years.
+    fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        if year < 1 {
+            Date::new(1, 1, 1)
+        } else if year > u16::MAX as i64 {
+            Date::new(u16::MAX, 12, 31)
+        } else {
+            Date::new(year as u16, month as u8, day as u8)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_roundtrip() {
+        assert_eq!(Date::new(1970, 1, 1).to_days(), 0);
+        assert_eq!(Date::from_days(0), Date::new(1970, 1, 1));
+
+        for &date in &[
+            Date::new(1, 1, 1),
+            Date::new(2000, 2, 29),
+            Date::new(2021, 12, 31),
+            Date::new(9999, 12, 31),
+        ] {
+            assert_eq!(Date::from_days(date.to_days()), date);
+        }
+    }
+
+    #[test]
+    fn test_weekday() {
+        // 2021-03-01 was a monday
+        assert_eq!(Date::new(2021, 3, 1).weekday(), 0);
+        assert_eq!(Date::new(2021, 3, 7).weekday(), 6);
+    }
+
+    #[test]
+    fn test_add_months_clamps_day() {
+        assert_eq!(Date::new(2024, 1, 31).add_months(1), Date::new(2024, 2, 29));
+        assert_eq!(
+            Date::new(2021, 1, 31).add_months(-2),
+            Date::new(2020, 11, 30)
+        );
+    }
+
+    #[test]
+    fn test_clamp_to_range() {
+        let mut picker = DatePicker::new();
+        picker.min_date(2021, 1, 10).max_date(2021, 2, 10);
+
+        assert_eq!(picker.clamp(Date::new(2021, 1, 1)), Date::new(2021, 1, 10));
+        assert_eq!(picker.clamp(Date::new(2021, 3, 1)), Date::new(2021, 2, 10));
+        assert_eq!(picker.clamp(Date::new(2021, 2, 1)), Date::new(2021, 2, 1));
+    }
+}
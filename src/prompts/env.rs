@@ -0,0 +1,77 @@
+use std::{env, io};
+
+/// Returns the value of the environment variable `var` if it is set and not empty.
+pub fn answer(var: Option<&str>) -> Option<String> {
+    let value = env::var(var?).ok()?;
+
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Returns the error for an answer read from `var` that is not valid.
+pub fn invalid_answer(var: &str, reason: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("Invalid answer in `{}`: {}", var, reason),
+    )
+}
+
+/// Returns the index of the item matching `value`.
+///
+/// The value is either the text of an item or its index.
+pub fn find_item<I, S>(var: &str, value: &str, items: I) -> io::Result<usize>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let value = value.trim();
+    let mut len = 0;
+
+    for (idx, item) in items.into_iter().enumerate() {
+        if item.as_ref() == value {
+            return Ok(idx);
+        }
+
+        len += 1;
+    }
+
+    match value.parse::<usize>() {
+        Ok(idx) if idx < len => Ok(idx),
+        _ => Err(invalid_answer(var, &format!("no item `{}`", value))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_item() {
+        let items = ["red", "green", "1"];
+
+        assert_eq!(find_item("COLOR", "green", &items).unwrap(), 1);
+        assert_eq!(find_item("COLOR", " red ", &items).unwrap(), 0);
+        // Item texts take precedence over indices
+        assert_eq!(find_item("COLOR", "1", &items).unwrap(), 2);
+        assert_eq!(find_item("COLOR", "0", &items).unwrap(), 0);
+        assert_eq!(
+            find_item("COLOR", "3", &items).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        assert!(find_item("COLOR", "blue", &items).is_err());
+    }
+
+    #[test]
+    fn test_answer() {
+        env::set_var("DIALOGUER_TEST_ANSWER", "yes");
+        env::set_var("DIALOGUER_TEST_ANSWER_EMPTY", "");
+
+        assert_eq!(answer(Some("DIALOGUER_TEST_ANSWER")), Some("yes".into()));
+        assert_eq!(answer(Some("DIALOGUER_TEST_ANSWER_EMPTY")), None);
+        assert_eq!(answer(Some("DIALOGUER_TEST_ANSWER_UNSET")), None);
+        assert_eq!(answer(None), None);
+    }
+}
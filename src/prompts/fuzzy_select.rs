@@ -0,0 +1,294 @@
+use std::{cmp::Reverse, io};
+
+use crate::{
+    prompts::search::SearchList,
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
+
+use console::Term;
+
+/// Renders a select prompt with a search field.
+///
+/// The user types into the search field at the top to narrow down the items,
+/// which are ranked by how well they match the search. Interaction returns
+/// the index of the selected item in the order the items were added.
+///
+/// ## Example usage
+///
+/// ```rust,no_run
+/// use dialoguer::{theme::ColorfulTheme, FuzzySelect};
+///
+/// fn main() -> std::io::Result<()> {
+///     let items = vec!["Vanilla Cupcake", "Chocolate Muffin", "A Pile of sweet, sweet mustard"];
+///     let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
+///         .with_prompt("Pick your flavor")
+///         .items(&items)
+///         .interact()?;
+///
+///     println!("Enjoy your {}!", items[selection]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct FuzzySelect<'a> {
+    default: usize,
+    items: Vec<String>,
+    prompt: String,
+    clear: bool,
+    highlight_matches: bool,
+    theme: &'a dyn Theme,
+}
+
+impl<'a> Default for FuzzySelect<'a> {
+    fn default() -> FuzzySelect<'a> {
+        FuzzySelect::new()
+    }
+}
+
+impl<'a> FuzzySelect<'a> {
+    /// Creates a fuzzy select prompt.
+    pub fn new() -> FuzzySelect<'static> {
+        FuzzySelect::with_theme(default_theme())
+    }
+
+    /// Creates a fuzzy select prompt with a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme) -> FuzzySelect<'a> {
+        FuzzySelect {
+            default: 0,
+            items: vec![],
+            prompt: "".into(),
+            clear: true,
+            highlight_matches: true,
+            theme,
+        }
+    }
+
+    /// Indicates whether the prompt should be erased from the screen after interaction.
+    ///
+    /// The default is to clear the prompt.
+    pub fn clear(&mut self, val: bool) -> &mut FuzzySelect<'a> {
+        self.clear = val;
+        self
+    }
+
+    /// Sets the item highlighted before the user starts searching.
+    pub fn default(&mut self, val: usize) -> &mut FuzzySelect<'a> {
+        self.default = val;
+        self
+    }
+
+    /// Indicates whether the characters matching the search should be highlighted.
+    ///
+    /// The default is to highlight them.
+    pub fn highlight_matches(&mut self, val: bool) -> &mut FuzzySelect<'a> {
+        self.highlight_matches = val;
+        self
+    }
+
+    /// Add a single item to the selector.
+    pub fn item<T: ToString>(&mut self, item: T) -> &mut FuzzySelect<'a> {
+        self.items.push(item.to_string());
+        self
+    }
+
+    /// Adds multiple items to the selector.
+    pub fn items<T, I>(&mut self, items: I) -> &mut FuzzySelect<'a>
+    where
+        T: ToString,
+        I: IntoIterator<Item = T>,
+    {
+        for item in items {
+            self.items.push(item.to_string());
+        }
+        self
+    }
+
+    /// Prefaces the search field with a prompt.
+    pub fn with_prompt<S: Into<String>>(&mut self, prompt: S) -> &mut FuzzySelect<'a> {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains index of the selected item.
+    pub fn interact(&self) -> io::Result<usize> {
+        self.interact_on(&Term::stderr())
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(index)` if the user selected an item or `None` if the user cancelled with 'Esc'.
+    pub fn interact_opt(&self) -> io::Result<Option<usize>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
+    /// Like [interact](#method.interact) but allows a specific terminal to be set.
+    pub fn interact_on(&self, term: &Term) -> io::Result<usize> {
+        self._interact_on(term, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<usize>> {
+        self._interact_on(term, true)
+    }
+
+    fn _interact_on(&self, mut term: &Term, allow_quit: bool) -> io::Result<Option<usize>> {
+        if self.items.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Empty list of items given to `FuzzySelect`",
+            ));
+        }
+
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let list = SearchList {
+            items: &self.items,
+            prompt: &self.prompt,
+            default: self.default,
+            clear: self.clear,
+        };
+
+        list.run(&mut term, &mut render, allow_quit, |search_term| {
+            let mut filtered = self.filter_items(search_term);
+
+            if !self.highlight_matches {
+                for (_, matches) in &mut filtered {
+                    matches.clear();
+                }
+            }
+
+            filtered
+        })
+    }
+
+    /// Returns the indices of the items matching `search_term`, best matches
+    /// first, along with the positions of their matching characters.
+    fn filter_items(&self, search_term: &str) -> Vec<(usize, Vec<usize>)> {
+        let mut scored: Vec<_> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| {
+                fuzzy_match(item, search_term).map(|(score, matches)| (idx, score, matches))
+            })
+            .collect();
+
+        // The sort is stable, so equally scored items keep their order.
+        scored.sort_by_key(|&(_, score, _)| Reverse(score));
+        scored
+            .into_iter()
+            .map(|(idx, _, matches)| (idx, matches))
+            .collect()
+    }
+}
+
+/// Scores `item` against `pattern` using a character subsequence match.
+///
+/// Returns `None` if the characters of `pattern` do not all appear in `item`
+/// in order (case-insensitive). Consecutive matches and matches at the start
+/// of a word score higher, gaps between matches lower the score.
+pub(crate) fn fuzzy_score(item: &str, pattern: &str) -> Option<i64> {
+    fuzzy_match(item, pattern).map(|(score, _)| score)
+}
+
+/// Like [fuzzy_score] but also returns the positions of the matching
+/// characters in `item`.
+fn fuzzy_match(item: &str, pattern: &str) -> Option<(i64, Vec<usize>)> {
+    let mut pattern = pattern.chars().flat_map(char::to_lowercase).peekable();
+    let mut score = 0;
+    let mut matches = vec![];
+    let mut started = false;
+    let mut prev_matched = false;
+    let mut prev_char: Option<char> = None;
+
+    'item: for (idx, orig) in item.chars().enumerate() {
+        for chr in orig.to_lowercase() {
+            let expected = match pattern.peek() {
+                Some(&expected) => expected,
+                None => break 'item,
+            };
+
+            if chr == expected {
+                score += 1;
+
+                if prev_matched {
+                    score += 4;
+                }
+
+                match prev_char {
+                    Some(prev) if prev.is_alphanumeric() => {}
+                    _ => score += 2,
+                }
+
+                if matches.last() != Some(&idx) {
+                    matches.push(idx);
+                }
+
+                started = true;
+                prev_matched = true;
+                pattern.next();
+            } else {
+                if started {
+                    score -= 1;
+                }
+
+                prev_matched = false;
+            }
+
+            prev_char = Some(chr);
+        }
+    }
+
+    if pattern.peek().is_some() {
+        None
+    } else {
+        Some((score, matches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_subsequence() {
+        assert!(fuzzy_score("Chocolate Muffin", "cmuf").is_some());
+        assert!(fuzzy_score("Chocolate Muffin", "CMUF").is_some());
+        assert!(fuzzy_score("Chocolate Muffin", "fumc").is_none());
+        assert_eq!(fuzzy_score("anything", ""), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_score_ranking() {
+        let exact = fuzzy_score("Vanilla Cupcake", "cup").unwrap();
+        let scattered = fuzzy_score("Chocolate Muffin Pie", "cup").unwrap();
+
+        assert!(exact > scattered);
+    }
+
+    #[test]
+    fn test_fuzzy_match_positions() {
+        assert_eq!(fuzzy_match("Cupcake", "cpk").unwrap().1, vec![0, 2, 5]);
+        assert_eq!(fuzzy_match("Cupcake", "").unwrap().1, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_filter_items_ranking() {
+        let mut select = FuzzySelect::new();
+        select.items(&["Chocolate Muffin Pie", "Vanilla Cupcake", "Lemon Tart"]);
+
+        let filtered: Vec<_> = select
+            .filter_items("cup")
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect();
+
+        assert_eq!(filtered, vec![1, 0]);
+    }
+}
@@ -1,15 +1,23 @@
 use std::{
+    cell::RefCell,
     fmt::{Debug, Display},
     io, iter,
+    rc::Rc,
     str::FromStr,
 };
 
 use crate::{
-    theme::{SimpleTheme, TermThemeRenderer, Theme},
+    completion::Completion,
+    history::History,
+    prompts::{env, keys::read_key_with_io},
+    theme::{default_theme, TermThemeRenderer, Theme},
     validate::Validator,
 };
 
-use console::{Key, Term};
+use console::{measure_text_width, Key, Term};
+
+/// Turns the entered text into the text shown, see [Input::with_transformer].
+type Transformer<'a> = Rc<dyn Fn(&str) -> String + 'a>;
 
 /// Renders an input prompt.
 ///
@@ -37,6 +45,21 @@ use console::{Key, Term};
 /// # Ok(())
 /// # }
 /// ```
+///
+/// Any type implementing `FromStr` can be read, the text is parsed into it.
+/// Input that fails to parse is rejected with the parse error and the user
+/// is asked again:
+///
+/// ```rust,no_run
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// # use dialoguer::Input;
+/// let age = Input::<u32>::new()
+///     .with_prompt("Your age")
+///     .interact_text()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
 pub struct Input<'a, T> {
     prompt: String,
     default: Option<T>,
@@ -44,7 +67,14 @@ pub struct Input<'a, T> {
     initial_text: Option<String>,
     theme: &'a dyn Theme,
     permit_empty: bool,
-    validator: Option<Box<dyn Fn(&T) -> Option<String> + 'a>>,
+    validator: Option<Rc<dyn Fn(&T) -> Option<String> + 'a>>,
+    completion: Option<&'a dyn Completion>,
+    history: Option<Rc<RefCell<&'a mut dyn History>>>,
+    mask_except_last: Option<usize>,
+    transformer: Option<Transformer<'a>>,
+    env_var: Option<String>,
+    multiline: bool,
+    multiline_height: usize,
 }
 
 impl<'a, T> Default for Input<'a, T>
@@ -64,7 +94,7 @@ where
 {
     /// Creates an input prompt.
     pub fn new() -> Input<'a, T> {
-        Input::with_theme(&SimpleTheme)
+        Input::with_theme(default_theme())
     }
 
     /// Creates an input prompt with a specific theme.
@@ -77,6 +107,13 @@ where
             theme,
             permit_empty: false,
             validator: None,
+            completion: None,
+            history: None,
+            mask_except_last: None,
+            transformer: None,
+            env_var: None,
+            multiline: false,
+            multiline_height: 10,
         }
     }
 
@@ -87,6 +124,9 @@ where
     }
 
     /// Sets initial text that user can accept or erase.
+    ///
+    /// The text is placed in the input buffer with the cursor at its end, so
+    /// unlike [`default`](#method.default) it can be edited before accepting.
     pub fn with_initial_text<S: Into<String>>(&mut self, val: S) -> &mut Input<'a, T> {
         self.initial_text = Some(val.into());
         self
@@ -105,15 +145,110 @@ where
     /// Enables or disables an empty input
     ///
     /// By default, if there is no default value set for the input, the user must input a non-empty string.
+    /// Submitting an empty input then shows the error "Value cannot be empty." and asks again.
     pub fn allow_empty(&mut self, val: bool) -> &mut Input<'a, T> {
         self.permit_empty = val;
         self
     }
 
+    /// Masks all but the last `n` characters of the input.
+    ///
+    /// Useful for fields like card numbers where only the end should be
+    /// readable on screen. The masked characters are rendered as `*`, the
+    /// returned value is always the text the user entered. Completion and
+    /// history are disabled for masked input.
+    ///
+    /// Masking only applies to [`interact_text`](#method.interact_text), as
+    /// [`interact`](#method.interact) leaves echoing the line to the terminal.
+    pub fn mask_except_last(&mut self, n: usize) -> &mut Input<'a, T> {
+        self.mask_except_last = Some(n);
+        self
+    }
+
+    /// Shows the input transformed by `transformer` instead of as entered.
+    ///
+    /// The transformer is called with the entered text after every key press,
+    /// e.g. to format a phone number while it is typed. The returned value is
+    /// always the text the user entered. As the transformed text may differ
+    /// in length the cursor stays at its end, and completion and history are
+    /// disabled.
+    ///
+    /// Like [`mask_except_last`](#method.mask_except_last) this only applies
+    /// to single line input read by [`interact_text`](#method.interact_text).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Input;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let host: String = Input::new()
+    ///     .with_prompt("Host")
+    ///     .with_transformer(|text| format!("https://{}", text))
+    ///     .interact_text()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_transformer<F>(&mut self, transformer: F) -> &mut Input<'a, T>
+    where
+        F: Fn(&str) -> String + 'a,
+    {
+        self.transformer = Some(Rc::new(transformer));
+        self
+    }
+
+    /// Enables or disables entering text that spans multiple lines.
+    ///
+    /// In multiline mode [`interact_text`](#method.interact_text) inserts a
+    /// newline on Enter and submits the text on Ctrl+D. Most terminals send
+    /// the same key for Ctrl+Enter as for Enter, so it cannot be used to
+    /// submit. The text is edited at its end, Backspace joins lines and
+    /// Ctrl+U clears the current line. The returned value keeps the newlines.
+    ///
+    /// Completion, history and masking are not available in multiline mode.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use dialoguer::Input;
+    /// let query: String = Input::new()
+    ///     .with_prompt("SQL query")
+    ///     .multiline(true)
+    ///     .interact_text()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn multiline(&mut self, val: bool) -> &mut Input<'a, T> {
+        self.multiline = val;
+        self
+    }
+
+    /// Sets how many lines of multiline text are shown at most.
+    ///
+    /// Once the text has more lines the earlier ones scroll out of view. The
+    /// default is 10.
+    pub fn multiline_height(&mut self, val: usize) -> &mut Input<'a, T> {
+        self.multiline_height = val;
+        self
+    }
+
+    /// Reads the answer from the environment variable `name` instead of the terminal.
+    ///
+    /// When the variable is set to a non-empty value the prompt is not shown
+    /// and the value is parsed and validated like text typed by the user.
+    /// An answer that is not valid results in an error of kind `InvalidInput`.
+    ///
+    /// This allows running the prompt non-interactively, e.g. in CI.
+    pub fn env_var<S: Into<String>>(&mut self, name: S) -> &mut Input<'a, T> {
+        self.env_var = Some(name.into());
+        self
+    }
+
     /// Disables or enables the default value display.
     ///
     /// The default behaviour is to append [`default`] to the prompt to tell the
-    /// user what is the default value.
+    /// user what is the default value. [`interact_text`](#method.interact_text)
+    /// instead shows it as dimmed ghost text in the empty input, which
+    /// disappears while the user is typing.
     ///
     /// This method does not affect existance of default value, only its display in the prompt!
     pub fn show_default(&mut self, val: bool) -> &mut Input<'a, T> {
@@ -123,6 +258,11 @@ where
 
     /// Registers a validator.
     ///
+    /// The validator runs every time the user submits a value. If it fails
+    /// its error message is rendered through the theme and the user is asked
+    /// again. Calling this method multiple times chains the validators, which
+    /// then run in the order they were registered.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -146,7 +286,7 @@ where
     {
         let old_validator_func = self.validator.take();
 
-        self.validator = Some(Box::new(move |value: &T| -> Option<String> {
+        self.validator = Some(Rc::new(move |value: &T| -> Option<String> {
             if let Some(old) = old_validator_func.as_ref() {
                 if let Some(err) = old(value) {
                     return Some(err);
@@ -162,11 +302,57 @@ where
         self
     }
 
+    /// Registers a completion.
+    ///
+    /// When the user presses Tab in [`interact_text`](#method.interact_text) the
+    /// completion is asked to complete the current input.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use dialoguer::{Completion, Input};
+    /// struct Commands;
+    ///
+    /// impl Completion for Commands {
+    ///     fn get(&self, input: &str) -> Option<String> {
+    ///         ["checkout", "commit", "clone"]
+    ///             .iter()
+    ///             .find(|cmd| cmd.starts_with(input))
+    ///             .map(|cmd| cmd.to_string())
+    ///     }
+    /// }
+    ///
+    /// let command: String = Input::new()
+    ///     .with_prompt("Command")
+    ///     .completion_with(&Commands)
+    ///     .interact_text()
+    ///     .unwrap();
+    /// ```
+    pub fn completion_with(&mut self, completion: &'a dyn Completion) -> &mut Input<'a, T> {
+        self.completion = Some(completion);
+        self
+    }
+
+    /// Registers a history.
+    ///
+    /// In [`interact_text`](#method.interact_text) the user can browse the
+    /// history with the up and down arrow keys. Accepted values are written
+    /// to the history. Clones of the prompt share the history.
+    pub fn history_with(&mut self, history: &'a mut dyn History) -> &mut Input<'a, T> {
+        self.history = Some(Rc::new(RefCell::new(history)));
+        self
+    }
+
     /// Enables the user to enter a printable ascii sequence and returns the result.
     ///
     /// Its difference from [`interact`](#method.interact) is that it only allows ascii characters for string,
     /// while [`interact`](#method.interact) allows virtually any character to be used e.g arrow keys.
     ///
+    /// The cursor can be moved within the input with the left and right arrow
+    /// keys as well as Home and End. Delete removes the character under the
+    /// cursor, Ctrl+W deletes the word before the cursor and Ctrl+U clears the
+    /// input.
+    ///
     /// The dialog is rendered on stderr.
     pub fn interact_text(&self) -> io::Result<T> {
         self.interact_text_on(&Term::stderr())
@@ -174,108 +360,111 @@ where
 
     /// Like [`interact_text`](#method.interact_text) but allows a specific terminal to be set.
     pub fn interact_text_on(&self, term: &Term) -> io::Result<T> {
-        let mut render = TermThemeRenderer::new(term, self.theme);
+        self.interact_text_on_with_io(term, term)
+    }
+
+    /// Like [`interact_text_on`](#method.interact_text_on) but reads keys from `input` and renders on `output`.
+    pub fn interact_text_on_with_io(&self, input: &Term, output: &Term) -> io::Result<T> {
+        self._interact_text_on(input, output, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [`interact_text`](#method.interact_text) but lets the user cancel with 'Esc'.
+    ///
+    /// Result contains `Some(value)` if the user entered a value or `None` if
+    /// the user cancelled with 'Esc'. The dialog is rendered on stderr.
+    pub fn interact_text_opt(&self) -> io::Result<Option<T>> {
+        self.interact_text_on_opt(&Term::stderr())
+    }
+
+    /// Like [`interact_text_opt`](#method.interact_text_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_text_on_opt(&self, term: &Term) -> io::Result<Option<T>> {
+        self._interact_text_on(term, term, true)
+    }
+
+    fn _interact_text_on(
+        &self,
+        input: &Term,
+        output: &Term,
+        allow_quit: bool,
+    ) -> io::Result<Option<T>> {
+        if let Some(value) = self.env_answer() {
+            return value.map(Some);
+        }
+
+        let mut render = TermThemeRenderer::new(output, self.theme);
 
         loop {
             let default_string = self.default.as_ref().map(|x| x.to_string());
 
+            // The default is shown as ghost text in the input, unless the
+            // terminal cannot tell it apart from the text the user typed.
+            let placeholder =
+                if self.show_default && !self.multiline && output.features().colors_supported() {
+                    default_string.as_deref()
+                } else {
+                    None
+                };
+
             render.input_prompt(
                 &self.prompt,
-                if self.show_default {
+                if self.show_default && placeholder.is_none() {
                     default_string.as_deref()
                 } else {
                     None
                 },
             )?;
-            term.flush()?;
+            output.flush()?;
 
             // Read input by keystroke so that we can suppress ascii control characters
-            if !term.features().is_attended() {
-                return Ok("".to_owned().parse::<T>().unwrap());
+            if !input.features().is_attended() {
+                return Ok(Some("".to_owned().parse::<T>().unwrap()));
             }
 
-            let mut chars: Vec<char> = Vec::new();
-            let mut position = 0;
-
-            if let Some(initial) = self.initial_text.as_ref() {
-                term.write_str(initial)?;
-                chars = initial.chars().collect();
-                position = chars.len();
-            }
-
-            loop {
-                match term.read_key()? {
-                    Key::Backspace if position > 0 => {
-                        position -= 1;
-                        chars.remove(position);
-                        term.clear_chars(1)?;
-
-                        let tail: String = chars[position..].iter().collect();
-
-                        if !tail.is_empty() {
-                            term.write_str(&tail)?;
-                            term.move_cursor_left(tail.len())?;
-                        }
+            let text = if self.multiline {
+                self.read_multiline(input, output, &mut render, allow_quit)?
+            } else {
+                self.read_keys(input, output, &mut render, placeholder, allow_quit)?
+            };
 
-                        term.flush()?;
-                    }
-                    Key::Char(chr) if !chr.is_ascii_control() => {
-                        chars.insert(position, chr);
-                        position += 1;
-                        let tail: String =
-                            iter::once(&chr).chain(chars[position..].iter()).collect();
-                        term.write_str(&tail)?;
-                        term.move_cursor_left(tail.len() - 1)?;
-                        term.flush()?;
-                    }
-                    Key::ArrowLeft if position > 0 => {
-                        term.move_cursor_left(1)?;
-                        position -= 1;
-                        term.flush()?;
-                    }
-                    Key::ArrowRight if position < chars.len() => {
-                        term.move_cursor_right(1)?;
-                        position += 1;
-                        term.flush()?;
-                    }
-                    Key::Enter => break,
-                    Key::Unknown => {
-                        return Err(io::Error::new(
-                            io::ErrorKind::NotConnected,
-                            "Not a terminal",
-                        ))
-                    }
-                    _ => (),
-                }
-            }
-            let input = chars.iter().collect::<String>();
+            let text = match text {
+                Some(text) => text,
+                None => return Ok(None),
+            };
 
-            term.clear_line()?;
+            output.clear_line()?;
             render.clear()?;
 
-            if chars.is_empty() {
+            if text.is_empty() {
                 if let Some(ref default) = self.default {
+                    self.write_history(&default.to_string());
                     render.input_prompt_selection(&self.prompt, &default.to_string())?;
-                    term.flush()?;
-                    return Ok(default.clone());
+                    output.flush()?;
+                    return Ok(Some(default.clone()));
                 } else if !self.permit_empty {
+                    render.validation_error("Value cannot be empty.")?;
                     continue;
                 }
             }
 
-            match input.parse::<T>() {
+            match text.parse::<T>() {
                 Ok(value) => {
                     if let Some(ref validator) = self.validator {
                         if let Some(err) = validator(&value) {
-                            render.error(&err)?;
+                            render.validation_error(&err)?;
                             continue;
                         }
                     }
 
-                    render.input_prompt_selection(&self.prompt, &input)?;
-                    term.flush()?;
+                    if self.mask_except_last.is_none() {
+                        self.write_history(&text);
+                    }
+
+                    render.input_prompt_selection(&self.prompt, &self.display_text(&text))?;
+                    output.flush()?;
 
-                    return Ok(value);
+                    return Ok(Some(value));
                 }
                 Err(err) => {
                     render.error(&err.to_string())?;
@@ -299,7 +488,16 @@ where
 
     /// Like [`interact`](#method.interact) but allows a specific terminal to be set.
     pub fn interact_on(&self, term: &Term) -> io::Result<T> {
-        let mut render = TermThemeRenderer::new(term, self.theme);
+        self.interact_on_with_io(term, term)
+    }
+
+    /// Like [`interact_on`](#method.interact_on) but reads the line from `input` and renders on `output`.
+    pub fn interact_on_with_io(&self, input: &Term, output: &Term) -> io::Result<T> {
+        if let Some(value) = self.env_answer() {
+            return value;
+        }
+
+        let mut render = TermThemeRenderer::new(output, self.theme);
 
         loop {
             let default_string = self.default.as_ref().map(|x| x.to_string());
@@ -312,39 +510,42 @@ where
                     None
                 },
             )?;
-            term.flush()?;
+            output.flush()?;
 
-            let input = if let Some(initial_text) = self.initial_text.as_ref() {
-                term.read_line_initial_text(initial_text)?
+            let text = if let Some(initial_text) = self.initial_text.as_ref() {
+                input.read_line_initial_text(initial_text)?
             } else {
-                term.read_line()?
+                input.read_line()?
             };
 
             render.add_line();
-            term.clear_line()?;
+            output.clear_line()?;
             render.clear()?;
 
-            if input.is_empty() {
+            if text.is_empty() {
                 if let Some(ref default) = self.default {
+                    self.write_history(&default.to_string());
                     render.input_prompt_selection(&self.prompt, &default.to_string())?;
-                    term.flush()?;
+                    output.flush()?;
                     return Ok(default.clone());
                 } else if !self.permit_empty {
+                    render.validation_error("Value cannot be empty.")?;
                     continue;
                 }
             }
 
-            match input.parse::<T>() {
+            match text.parse::<T>() {
                 Ok(value) => {
                     if let Some(ref validator) = self.validator {
                         if let Some(err) = validator(&value) {
-                            render.error(&err)?;
+                            render.validation_error(&err)?;
                             continue;
                         }
                     }
 
-                    render.input_prompt_selection(&self.prompt, &input)?;
-                    term.flush()?;
+                    self.write_history(&text);
+                    render.input_prompt_selection(&self.prompt, &text)?;
+                    output.flush()?;
 
                     return Ok(value);
                 }
@@ -355,4 +556,530 @@ where
             }
         }
     }
+
+    /// Reads a line key by key, allowing the cursor to be moved within it.
+    ///
+    /// Returns `None` if the user cancelled with 'Esc'.
+    fn read_keys(
+        &self,
+        input: &Term,
+        output: &Term,
+        render: &mut TermThemeRenderer,
+        placeholder: Option<&str>,
+        allow_quit: bool,
+    ) -> io::Result<Option<String>> {
+        let mut chars: Vec<char> = Vec::new();
+        let mut position = 0;
+        let mut history_pos: Option<usize> = None;
+
+        if let Some(initial) = self.initial_text.as_ref() {
+            output.write_str(&self.display_text(initial))?;
+            chars = initial.chars().collect();
+            position = chars.len();
+        }
+
+        let mut placeholder_shown = false;
+
+        loop {
+            if chars.is_empty() && !placeholder_shown {
+                if let Some(placeholder) = placeholder {
+                    self.show_placeholder(output, placeholder)?;
+                    placeholder_shown = true;
+                }
+            }
+
+            let key = read_key_with_io(input, output)?;
+
+            if placeholder_shown {
+                hide_placeholder(output, placeholder.unwrap_or_default())?;
+                placeholder_shown = false;
+            }
+
+            match key {
+                // Edits happen at the end of a transformed input, see `with_transformer`
+                Key::Backspace if self.transformer.is_some() && position > 0 => {
+                    let old = self.display_text(&chars.iter().collect::<String>());
+                    chars.pop();
+                    position -= 1;
+                    self.redraw_transformed(output, &old, &chars)?;
+                }
+                Key::Char(chr) if self.transformer.is_some() && !chr.is_ascii_control() => {
+                    let old = self.display_text(&chars.iter().collect::<String>());
+                    chars.push(chr);
+                    position += 1;
+                    self.redraw_transformed(output, &old, &chars)?;
+                }
+                Key::Char('\x17') if self.transformer.is_some() => {
+                    let old = self.display_text(&chars.iter().collect::<String>());
+                    position = word_start(&chars, position);
+                    chars.truncate(position);
+                    self.redraw_transformed(output, &old, &chars)?;
+                }
+                Key::Char('\x15') if self.transformer.is_some() => {
+                    let old = self.display_text(&chars.iter().collect::<String>());
+                    chars.clear();
+                    position = 0;
+                    self.redraw_transformed(output, &old, &chars)?;
+                }
+                Key::Backspace
+                | Key::Del
+                | Key::ArrowLeft
+                | Key::ArrowRight
+                | Key::Home
+                | Key::End
+                | Key::Tab
+                | Key::ArrowUp
+                | Key::ArrowDown
+                    if self.transformer.is_some() => {}
+                Key::Backspace if self.mask_except_last.is_some() && position > 0 => {
+                    chars.remove(position - 1);
+                    self.redraw_masked(output, &chars, position, chars.len() + 1, position - 1)?;
+                    position -= 1;
+                }
+                Key::Del if self.mask_except_last.is_some() && position < chars.len() => {
+                    chars.remove(position);
+                    self.redraw_masked(output, &chars, position, chars.len() + 1, position)?;
+                }
+                Key::Char(chr) if self.mask_except_last.is_some() && !chr.is_ascii_control() => {
+                    chars.insert(position, chr);
+                    self.redraw_masked(output, &chars, position, chars.len() - 1, position + 1)?;
+                    position += 1;
+                }
+                Key::Char('\x17') if self.mask_except_last.is_some() && position > 0 => {
+                    let start = word_start(&chars, position);
+                    chars.drain(start..position);
+                    self.redraw_masked(
+                        output,
+                        &chars,
+                        position,
+                        chars.len() + position - start,
+                        start,
+                    )?;
+                    position = start;
+                }
+                Key::Tab | Key::ArrowUp | Key::ArrowDown if self.mask_except_last.is_some() => {}
+                Key::Backspace if position > 0 => {
+                    position -= 1;
+                    chars.remove(position);
+                    output.clear_chars(1)?;
+
+                    let tail: String = chars[position..].iter().collect();
+
+                    if !tail.is_empty() {
+                        output.write_str(&tail)?;
+                        output.move_cursor_left(tail.len())?;
+                    }
+
+                    output.flush()?;
+                }
+                Key::Del if position < chars.len() => {
+                    chars.remove(position);
+
+                    // Shift the rest of the input left over the removed character
+                    let tail: String = chars[position..].iter().collect();
+                    output.write_str(&tail)?;
+                    output.write_str(" ")?;
+                    output.move_cursor_left(chars.len() - position + 1)?;
+                    output.flush()?;
+                }
+                Key::Char(chr) if !chr.is_ascii_control() => {
+                    chars.insert(position, chr);
+                    position += 1;
+                    let tail: String = iter::once(&chr).chain(chars[position..].iter()).collect();
+                    output.write_str(&tail)?;
+                    output.move_cursor_left(tail.len() - 1)?;
+                    output.flush()?;
+                }
+                Key::ArrowLeft if position > 0 => {
+                    output.move_cursor_left(1)?;
+                    position -= 1;
+                    output.flush()?;
+                }
+                Key::ArrowRight if position < chars.len() => {
+                    output.move_cursor_right(1)?;
+                    position += 1;
+                    output.flush()?;
+                }
+                Key::Char('\x17') if position > 0 => {
+                    // Ctrl+W removes the word before the cursor
+                    let start = word_start(&chars, position);
+                    let removed = position - start;
+                    output.move_cursor_left(removed)?;
+                    chars.drain(start..position);
+                    position = start;
+
+                    let tail: String = chars[position..].iter().collect();
+                    output.write_str(&tail)?;
+                    output.write_str(&" ".repeat(removed))?;
+                    output.move_cursor_left(chars.len() - position + removed)?;
+                    output.flush()?;
+                }
+                Key::Char('\x15') => {
+                    // Ctrl+U clears the whole input
+                    clear_input(output, &chars, position)?;
+                    chars.clear();
+                    position = 0;
+                    output.flush()?;
+                }
+                Key::Home if position > 0 => {
+                    output.move_cursor_left(position)?;
+                    position = 0;
+                    output.flush()?;
+                }
+                Key::End if position < chars.len() => {
+                    output.move_cursor_right(chars.len() - position)?;
+                    position = chars.len();
+                    output.flush()?;
+                }
+                Key::Tab => {
+                    if let Some(completion) = self.completion {
+                        let text: String = chars.iter().collect();
+
+                        if let Some(completed) = completion.get(&text) {
+                            clear_input(output, &chars, position)?;
+
+                            // Highlight the part the completion added to the input
+                            let prefix_len = if completed.starts_with(&text) {
+                                text.len()
+                            } else {
+                                0
+                            };
+                            output.write_str(&completed[..prefix_len])?;
+                            render.input_completion(&completed[prefix_len..])?;
+
+                            chars = completed.chars().collect();
+                            position = chars.len();
+                            output.flush()?;
+                        }
+                    }
+                }
+                Key::ArrowUp => {
+                    if let Some(ref history) = self.history {
+                        let pos = history_pos.map_or(0, |pos| pos + 1);
+
+                        if let Some(entry) = history.borrow().read(pos) {
+                            clear_input(output, &chars, position)?;
+                            output.write_str(&entry)?;
+
+                            chars = entry.chars().collect();
+                            position = chars.len();
+                            history_pos = Some(pos);
+                            output.flush()?;
+                        }
+                    }
+                }
+                Key::ArrowDown => {
+                    if let (Some(history), Some(pos)) = (self.history.as_ref(), history_pos) {
+                        // Moving past the most recent entry restores an empty input
+                        history_pos = pos.checked_sub(1);
+                        let entry = history_pos
+                            .and_then(|pos| history.borrow().read(pos))
+                            .unwrap_or_default();
+
+                        clear_input(output, &chars, position)?;
+                        output.write_str(&entry)?;
+
+                        chars = entry.chars().collect();
+                        position = chars.len();
+                        output.flush()?;
+                    }
+                }
+                Key::Enter => break,
+                Key::Escape if allow_quit => {
+                    output.clear_line()?;
+                    render.clear()?;
+                    output.flush()?;
+
+                    return Ok(None);
+                }
+                Key::Unknown => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotConnected,
+                        "Not a terminal",
+                    ))
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Some(chars.iter().collect()))
+    }
+
+    /// Reads text spanning multiple lines below the prompt.
+    ///
+    /// Only the last [multiline_height](#method.multiline_height) lines are
+    /// shown. Returns `None` if the user cancelled with 'Esc'.
+    fn read_multiline(
+        &self,
+        input: &Term,
+        output: &Term,
+        render: &mut TermThemeRenderer,
+        allow_quit: bool,
+    ) -> io::Result<Option<String>> {
+        let mut lines: Vec<String> = match self.initial_text {
+            Some(ref initial) => initial.split('\n').map(String::from).collect(),
+            None => vec![String::new()],
+        };
+        let mut shown = 0;
+
+        output.write_line("")?;
+
+        loop {
+            clear_rows(output, shown)?;
+
+            let visible = &lines[lines.len().saturating_sub(self.multiline_height.max(1))..];
+            output.write_str(&visible.join("\n"))?;
+            output.flush()?;
+            shown = text_rows(visible, output.size().1 as usize);
+
+            match read_key_with_io(input, output)? {
+                Key::Char('\x04') => break,
+                Key::Enter => lines.push(String::new()),
+                Key::Backspace => {
+                    let last = lines.last_mut().expect("lines are never empty");
+
+                    if last.pop().is_none() && lines.len() > 1 {
+                        lines.pop();
+                    }
+                }
+                Key::Char('\x15') => {
+                    // Ctrl+U clears the current line
+                    lines.last_mut().expect("lines are never empty").clear();
+                }
+                Key::Char(chr) if !chr.is_ascii_control() => {
+                    lines.last_mut().expect("lines are never empty").push(chr);
+                }
+                Key::Escape if allow_quit => {
+                    clear_rows(output, shown + 1)?;
+                    output.clear_line()?;
+                    render.clear()?;
+                    output.flush()?;
+
+                    return Ok(None);
+                }
+                Key::Unknown => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotConnected,
+                        "Not a terminal",
+                    ))
+                }
+                _ => (),
+            }
+        }
+
+        // Leave the cursor on the prompt line, which the caller clears
+        clear_rows(output, shown + 1)?;
+
+        Ok(Some(lines.join("\n")))
+    }
+
+    /// Returns the answer from the [env_var](#method.env_var) if it is set.
+    fn env_answer(&self) -> Option<io::Result<T>> {
+        let var = self.env_var.as_ref()?;
+        let text = env::answer(Some(var))?;
+
+        Some(match text.parse::<T>() {
+            Ok(value) => match self.validator {
+                Some(ref validator) => match validator(&value) {
+                    Some(err) => Err(env::invalid_answer(var, &err)),
+                    None => Ok(value),
+                },
+                None => Ok(value),
+            },
+            Err(err) => Err(env::invalid_answer(var, &err.to_string())),
+        })
+    }
+
+    /// Writes the default as ghost text after the cursor.
+    fn show_placeholder(&self, term: &Term, placeholder: &str) -> io::Result<()> {
+        let mut buf = String::new();
+        self.theme
+            .format_input_placeholder(&mut buf, placeholder)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+        term.write_str(&buf)?;
+        term.move_cursor_left(measure_text_width(placeholder))?;
+        term.flush()
+    }
+
+    /// Returns `text` as it is shown on screen.
+    fn display_text(&self, text: &str) -> String {
+        let text = match self.mask_except_last {
+            Some(visible) => mask_text(text, visible),
+            None => text.to_string(),
+        };
+
+        match self.transformer {
+            Some(ref transformer) => transformer(&text),
+            None => text,
+        }
+    }
+
+    /// Replaces the transformed input `old` in front of the cursor with the
+    /// transformation of `chars`.
+    fn redraw_transformed(&self, term: &Term, old: &str, chars: &[char]) -> io::Result<()> {
+        let new = self.display_text(&chars.iter().collect::<String>());
+        let old_width = measure_text_width(old);
+        let new_width = measure_text_width(&new);
+
+        if old_width > 0 {
+            term.move_cursor_left(old_width)?;
+        }
+
+        term.write_str(&new)?;
+
+        // Blank out characters left over from a longer text
+        if old_width > new_width {
+            term.write_str(&" ".repeat(old_width - new_width))?;
+            term.move_cursor_left(old_width - new_width)?;
+        }
+
+        term.flush()
+    }
+
+    /// Rewrites the whole masked input after an edit, as changing the length
+    /// moves the boundary between masked and visible characters.
+    ///
+    /// `cursor` is where the cursor was before the edit, `old_len` the
+    /// previous length of the input and `position` the new cursor position.
+    fn redraw_masked(
+        &self,
+        term: &Term,
+        chars: &[char],
+        cursor: usize,
+        old_len: usize,
+        position: usize,
+    ) -> io::Result<()> {
+        if cursor > 0 {
+            term.move_cursor_left(cursor)?;
+        }
+
+        term.write_str(&self.display_text(&chars.iter().collect::<String>()))?;
+
+        // Blank out characters left over from a longer input
+        let end = old_len.max(chars.len());
+        term.write_str(&" ".repeat(end - chars.len()))?;
+
+        if end > position {
+            term.move_cursor_left(end - position)?;
+        }
+
+        term.flush()
+    }
+
+    fn write_history(&self, text: &str) {
+        if let Some(ref history) = self.history {
+            history.borrow_mut().write(text);
+        }
+    }
+}
+
+/// Clears the `rows` rows above and including the one of the cursor and
+/// moves the cursor to the start of the topmost one.
+fn clear_rows(term: &Term, rows: usize) -> io::Result<()> {
+    if rows > 0 {
+        term.clear_line()?;
+        term.clear_last_lines(rows - 1)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the number of terminal rows `lines` take up on a terminal `width`
+/// columns wide.
+fn text_rows(lines: &[String], width: usize) -> usize {
+    lines
+        .iter()
+        .map(|line| match (measure_text_width(line), width) {
+            (0, _) | (_, 0) => 1,
+            (len, width) => 1 + (len - 1) / width,
+        })
+        .sum()
+}
+
+/// Erases the ghost text of the default, the cursor is at its start.
+fn hide_placeholder(term: &Term, placeholder: &str) -> io::Result<()> {
+    let width = measure_text_width(placeholder);
+    term.write_str(&" ".repeat(width))?;
+    term.move_cursor_left(width)
+}
+
+/// Erases the text the user entered so far from the terminal.
+fn clear_input(term: &Term, chars: &[char], position: usize) -> io::Result<()> {
+    if position < chars.len() {
+        term.move_cursor_right(chars.len() - position)?;
+    }
+
+    if !chars.is_empty() {
+        term.clear_chars(chars.len())?;
+    }
+
+    Ok(())
+}
+
+/// Replaces all but the last `visible` characters of `text` with `*`.
+fn mask_text(text: &str, visible: usize) -> String {
+    let masked = text.chars().count().saturating_sub(visible);
+
+    text.chars()
+        .enumerate()
+        .map(|(idx, chr)| if idx < masked { '*' } else { chr })
+        .collect()
+}
+
+/// Returns the position at which the word in front of `position` starts.
+///
+/// Whitespace directly before `position` belongs to the word.
+fn word_start(chars: &[char], position: usize) -> usize {
+    let mut start = position;
+
+    while start > 0 && chars[start - 1].is_whitespace() {
+        start -= 1;
+    }
+
+    while start > 0 && !chars[start - 1].is_whitespace() {
+        start -= 1;
+    }
+
+    start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_rows() {
+        let lines = vec!["".to_string(), "SELECT *".to_string(), "x".repeat(25)];
+
+        assert_eq!(text_rows(&lines, 80), 3);
+        assert_eq!(text_rows(&lines, 10), 5);
+    }
+
+    #[test]
+    fn test_word_start() {
+        let chars: Vec<char> = "git commit  -m".chars().collect();
+
+        assert_eq!(word_start(&chars, chars.len()), 12);
+        assert_eq!(word_start(&chars, 12), 4);
+        assert_eq!(word_start(&chars, 6), 4);
+        assert_eq!(word_start(&chars, 3), 0);
+        assert_eq!(word_start(&chars, 0), 0);
+    }
+
+    #[test]
+    fn test_display_text_transformer() {
+        let mut input = Input::<String>::new();
+        input.with_transformer(|text| format!("https://{}", text));
+
+        assert_eq!(input.display_text("example.com"), "https://example.com");
+    }
+
+    #[test]
+    fn test_mask_text() {
+        assert_eq!(mask_text("4111111111111111", 4), "************1111");
+        assert_eq!(mask_text("123", 4), "123");
+        assert_eq!(mask_text("1234", 0), "****");
+        assert_eq!(mask_text("", 4), "");
+    }
 }
@@ -0,0 +1,157 @@
+use std::io;
+
+use crate::{
+    prompts::keys::KeySource,
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
+
+use console::{Key, Term};
+
+/// Renders a prompt that waits for a key press.
+///
+/// Useful for "press any key to continue" steps of a wizard. Interaction
+/// returns the key that was pressed.
+///
+/// ## Example usage
+///
+/// ```rust,no_run
+/// use dialoguer::{console::Key, KeyPress};
+///
+/// fn main() -> std::io::Result<()> {
+///     KeyPress::new()
+///         .with_prompt("Press Enter to continue")
+///         .accepted_keys(&[Key::Enter])
+///         .interact()?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyPress<'a> {
+    prompt: String,
+    accepted_keys: Vec<Key>,
+    clear: bool,
+    theme: &'a dyn Theme,
+}
+
+impl<'a> Default for KeyPress<'a> {
+    fn default() -> KeyPress<'a> {
+        KeyPress::new()
+    }
+}
+
+impl<'a> KeyPress<'a> {
+    /// Creates a key press prompt.
+    pub fn new() -> KeyPress<'static> {
+        KeyPress::with_theme(default_theme())
+    }
+
+    /// Creates a key press prompt with a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme) -> KeyPress<'a> {
+        KeyPress {
+            prompt: "".into(),
+            accepted_keys: vec![],
+            clear: true,
+            theme,
+        }
+    }
+
+    /// Sets the message shown while waiting for the key press.
+    pub fn with_prompt<S: Into<String>>(&mut self, prompt: S) -> &mut KeyPress<'a> {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the keys that end the prompt, other keys are ignored.
+    ///
+    /// If no keys are set, which is the default, any key is accepted.
+    pub fn accepted_keys(&mut self, keys: &[Key]) -> &mut KeyPress<'a> {
+        self.accepted_keys = keys.to_vec();
+        self
+    }
+
+    /// Indicates whether the prompt should be erased from the screen after interaction.
+    ///
+    /// The default is to clear the prompt.
+    pub fn clear(&mut self, val: bool) -> &mut KeyPress<'a> {
+        self.clear = val;
+        self
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The prompt is rendered on stderr.
+    /// Result contains the key that was pressed.
+    pub fn interact(&self) -> io::Result<Key> {
+        self.interact_on(&Term::stderr())
+    }
+
+    /// Like [interact](#method.interact) but allows a specific terminal to be set.
+    pub fn interact_on(&self, mut term: &Term) -> io::Result<Key> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        self.run(&mut term, &mut render)
+    }
+
+    fn run(&self, input: &mut dyn KeySource, render: &mut TermThemeRenderer) -> io::Result<Key> {
+        render.key_press_prompt(&self.prompt)?;
+        render.hide_cursor()?;
+        render.flush()?;
+
+        loop {
+            let key = match input.read_key()? {
+                Key::Unknown => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotConnected,
+                        "Not a terminal",
+                    ))
+                }
+                key => key,
+            };
+
+            if self.accepted_keys.is_empty() || self.accepted_keys.contains(&key) {
+                if self.clear {
+                    render.clear()?;
+                }
+
+                render.show_cursor()?;
+                render.flush()?;
+
+                return Ok(key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::prompts::keys::ScriptedKeys;
+
+    fn run_keys(prompt: &KeyPress, keys: Vec<Key>) -> io::Result<Key> {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, prompt.theme);
+        render.capture_output();
+
+        prompt.run(&mut ScriptedKeys(keys.into_iter()), &mut render)
+    }
+
+    #[test]
+    fn test_any_key() {
+        let prompt = KeyPress::new();
+
+        assert_eq!(
+            run_keys(&prompt, vec![Key::Char('x')]).unwrap(),
+            Key::Char('x')
+        );
+    }
+
+    #[test]
+    fn test_accepted_keys() {
+        let mut prompt = KeyPress::new();
+        prompt.accepted_keys(&[Key::Enter, Key::Escape]);
+
+        let keys = vec![Key::Char('x'), Key::ArrowUp, Key::Escape];
+        assert_eq!(run_keys(&prompt, keys).unwrap(), Key::Escape);
+    }
+}
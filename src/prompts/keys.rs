@@ -0,0 +1,104 @@
+use std::io;
+
+use crate::prompts::mouse;
+
+use console::{Key, Term};
+
+/// A source of key presses for the interaction loop of a prompt.
+pub trait KeySource {
+    /// Returns the next key pressed.
+    fn read_key(&mut self) -> io::Result<Key>;
+}
+
+impl KeySource for &Term {
+    fn read_key(&mut self) -> io::Result<Key> {
+        mouse::read_key(self)
+    }
+}
+
+/// Reads a key from `term`, see [read_key_with_io](fn.read_key_with_io.html).
+pub fn read_key(term: &Term) -> io::Result<Key> {
+    read_key_with_io(term, term)
+}
+
+/// Reads a key from `input`, restoring the cursor on `output` if the user
+/// pressed Ctrl+C.
+///
+/// Console reports Ctrl+C as an error of kind `Interrupted` rather than as a
+/// key, after raising SIGINT. Programs that handle SIGINT get that error back
+/// from the prompt.
+pub fn read_key_with_io(input: &Term, output: &Term) -> io::Result<Key> {
+    check_interrupted(input.read_key(), output)
+}
+
+fn check_interrupted(key: io::Result<Key>, output: &Term) -> io::Result<Key> {
+    match key {
+        Err(ref err) if err.kind() == io::ErrorKind::Interrupted => interrupted(output),
+        key => key,
+    }
+}
+
+/// Restores the cursor on `term` and returns the error of an interaction the
+/// user interrupted with Ctrl+C.
+pub fn interrupted<T>(term: &Term) -> io::Result<T> {
+    term.show_cursor()?;
+    term.flush()?;
+    Err(io::Error::new(
+        io::ErrorKind::Interrupted,
+        "User pressed Ctrl-C",
+    ))
+}
+
+/// Plays back a fixed sequence of keys.
+pub struct ScriptedKeys<I>(pub I);
+
+impl<I: Iterator<Item = Key>> KeySource for ScriptedKeys<I> {
+    fn read_key(&mut self) -> io::Result<Key> {
+        self.0.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Ran out of keys before the prompt was done",
+            )
+        })
+    }
+}
+
+/// Reads a key from `term` on a blocking thread of the tokio runtime.
+#[cfg(feature = "tokio")]
+pub async fn read_key_async(term: &Term) -> io::Result<Key> {
+    let term = term.clone();
+    tokio::task::spawn_blocking(move || mouse::read_key(&term))
+        .await
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?
+}
+
+/// Reads a key from `term` on a blocking thread of the async-std runtime.
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub async fn read_key_async(term: &Term) -> io::Result<Key> {
+    let term = term.clone();
+    async_std::task::spawn_blocking(move || mouse::read_key(&term)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_interrupted() {
+        let term = Term::stderr();
+
+        let err = io::Error::new(io::ErrorKind::Interrupted, "read interrupted");
+        let err = check_interrupted(Err(err), &term).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert_eq!(err.to_string(), "User pressed Ctrl-C");
+
+        let err = io::Error::new(io::ErrorKind::UnexpectedEof, "Reached end of file");
+        let err = check_interrupted(Err(err), &term).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        assert_eq!(
+            check_interrupted(Ok(Key::Enter), &term).unwrap(),
+            Key::Enter
+        );
+    }
+}
@@ -1,6 +1,19 @@
+pub mod checkbox;
 pub mod confirm;
+mod cursor;
+pub mod date_picker;
+mod env;
+pub mod fuzzy_select;
 pub mod input;
+pub mod key_press;
+mod keys;
+mod mouse;
 pub mod multi_select;
 pub mod password;
+pub mod progress;
+mod search;
 pub mod select;
+pub mod select_with_search;
 pub mod sort;
+pub mod spinner;
+pub mod table_select;
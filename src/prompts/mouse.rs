@@ -0,0 +1,153 @@
+use std::io;
+
+use crate::prompts::keys;
+
+use console::{Key, Term};
+
+/// Enables mouse reporting on a terminal for as long as it is alive.
+///
+/// Terminals without ANSI support are left untouched, so prompts fall back
+/// to keyboard navigation there.
+pub struct MouseCapture<'a> {
+    term: &'a Term,
+    enabled: bool,
+}
+
+impl<'a> MouseCapture<'a> {
+    /// Starts capturing mouse events on `term` if `enable` is set.
+    pub fn new(term: &'a Term, enable: bool) -> io::Result<MouseCapture<'a>> {
+        let enabled = enable && term.features().is_attended() && term.features().colors_supported();
+
+        if enabled {
+            // Basic mouse tracking with SGR encoded reports
+            term.write_str("\x1b[?1000h\x1b[?1006h")?;
+        }
+
+        Ok(MouseCapture { term, enabled })
+    }
+}
+
+impl<'a> Drop for MouseCapture<'a> {
+    fn drop(&mut self) {
+        if self.enabled {
+            let _ = self.term.write_str("\x1b[?1006l\x1b[?1000l");
+        }
+    }
+}
+
+/// Reads a key from `term`, reporting mouse wheel events as arrow keys.
+pub fn read_key(term: &Term) -> io::Result<Key> {
+    let key = keys::read_key(term)?;
+    read_report(key, || keys::read_key(term))
+}
+
+/// Completes a mouse report that `key` starts and translates it.
+///
+/// Console stops reading an escape sequence after its third character, the
+/// rest of a mouse report arrives as separate keys, which are read with
+/// `next`. Wheel events become arrow keys, other reports are returned as an
+/// unknown escape sequence so they are not taken for typed characters.
+fn read_report<F>(key: Key, mut next: F) -> io::Result<Key>
+where
+    F: FnMut() -> io::Result<Key>,
+{
+    let mut seq = match key {
+        Key::UnknownEscSeq(seq) => seq,
+        key => return Ok(key),
+    };
+
+    match seq[..] {
+        // SGR reports end with 'M' on press and 'm' on release
+        ['[', '<', ..] => {
+            while !matches!(seq.last(), Some('M') | Some('m')) {
+                match next()? {
+                    Key::Char(c) => seq.push(c),
+                    _ => break,
+                }
+            }
+        }
+        // Legacy reports are followed by the button and two coordinate bytes
+        ['[', 'M', ..] => {
+            for _ in seq.len()..5 {
+                if let Key::Char(c) = next()? {
+                    seq.push(c);
+                }
+            }
+        }
+        _ => return Ok(Key::UnknownEscSeq(seq)),
+    }
+
+    Ok(match wheel_button(&seq) {
+        Some(64) => Key::ArrowUp,
+        Some(65) => Key::ArrowDown,
+        _ => Key::UnknownEscSeq(seq),
+    })
+}
+
+/// Returns the button of a mouse report, without modifiers.
+///
+/// `seq` is the escape sequence without the leading escape character, either
+/// in SGR (`[<64;10;5M`) or in legacy (`[M` followed by three bytes) encoding.
+fn wheel_button(seq: &[char]) -> Option<u32> {
+    let button = match seq {
+        ['[', '<', rest @ ..] => {
+            if rest.last() != Some(&'M') {
+                return None;
+            }
+
+            rest.iter()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse::<u32>()
+                .ok()?
+        }
+        ['[', 'M', button, ..] => (*button as u32).checked_sub(32)?,
+        _ => return None,
+    };
+
+    // Shift, meta and control are reported in bits 2 to 4
+    Some(button & !0b11100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wheel_button() {
+        let parse = |seq: &str| wheel_button(&seq.chars().collect::<Vec<_>>());
+
+        assert_eq!(parse("[<64;10;5M"), Some(64));
+        assert_eq!(parse("[<65;1;1M"), Some(65));
+        assert_eq!(parse("[<69;1;1M"), Some(65));
+        assert_eq!(parse("[<0;1;1m"), None);
+        assert_eq!(parse("[M`!!"), Some(64));
+        assert_eq!(parse("[Ma!!"), Some(65));
+        assert_eq!(parse("[A"), None);
+    }
+
+    #[test]
+    fn test_read_report() {
+        // Replays the keys console returns for a report
+        let read = |report: &str| {
+            let mut chars = report.chars().skip(4);
+            let key = Key::UnknownEscSeq(report.chars().skip(1).take(3).collect());
+            let key = read_report(key, || Ok(chars.next().map_or(Key::Enter, Key::Char)));
+            (key.unwrap(), chars.collect::<String>())
+        };
+
+        assert_eq!(read("\x1b[<64;10;5Mx"), (Key::ArrowUp, "x".into()));
+        assert_eq!(read("\x1b[<65;1;1M"), (Key::ArrowDown, "".into()));
+        assert_eq!(read("\x1b[Ma!!x").0, Key::ArrowDown);
+        assert_eq!(read("\x1b[Ma!!x").1, "x");
+
+        let (key, rest) = read("\x1b[<0;10;5mx");
+        assert_eq!(key, Key::UnknownEscSeq("[<0;10;5m".chars().collect()));
+        assert_eq!(rest, "x");
+
+        assert_eq!(
+            read("\x1b[2~x"),
+            (Key::UnknownEscSeq(vec!['[', '2', '~']), "x".into())
+        );
+    }
+}
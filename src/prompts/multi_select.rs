@@ -1,9 +1,38 @@
-use std::{io, iter::repeat, ops::Rem};
+use std::{
+    borrow::Borrow,
+    cell::RefCell,
+    cmp::Reverse,
+    collections::HashMap,
+    io,
+    ops::Rem,
+    process::{Command, Stdio},
+};
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+use std::{future::Future, pin::Pin, rc::Rc};
 
-use crate::theme::{SimpleTheme, TermThemeRenderer, Theme};
+use crate::{
+    prompts::{
+        cursor::CursorGuard,
+        env,
+        fuzzy_select::fuzzy_score,
+        keys::{KeySource, ScriptedKeys},
+        mouse::MouseCapture,
+    },
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
 
 use console::{Key, Term};
 
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+use crate::prompts::keys::read_key_async;
+
+/// The index of the entry added by [MultiSelect::with_select_all_item].
+const SELECT_ALL: usize = usize::MAX;
+
+/// Loads the items matching a search query.
+#[cfg(any(feature = "tokio", feature = "async-std"))]
+type ItemsLoader<'a> = Rc<dyn Fn(&str) -> Pin<Box<dyn Future<Output = Vec<String>> + 'a>> + 'a>;
+
 /// Renders a multi select prompt.
 ///
 /// ## Example usage
@@ -18,14 +47,110 @@ use console::{Key, Term};
 /// # Ok(())
 /// # }
 /// ```
-pub struct MultiSelect<'a> {
+///
+/// Items can carry values of any type, which are returned instead of the
+/// indices:
+///
+/// ```rust,no_run
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// use dialoguer::MultiSelect;
+///
+/// #[derive(Clone, Debug)]
+/// enum Topping {
+///     Cheese,
+///     Olives,
+/// }
+///
+/// let toppings: Vec<&Topping> = MultiSelect::valued()
+///     .items_with_values(vec![("Cheese", Topping::Cheese), ("Olives", Topping::Olives)])
+///     .interact_values()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct MultiSelect<'a, V = ()> {
     defaults: Vec<bool>,
-    items: Vec<String>,
+    explicit_defaults: Option<Vec<bool>>,
+    items: RefCell<Vec<String>>,
+    source: RefCell<Option<Box<dyn Iterator<Item = String> + 'a>>>,
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    loader: Option<ItemsLoader<'a>>,
+    descriptions: Vec<Option<String>>,
+    disabled: Vec<bool>,
     prompt: Option<String>,
     clear: bool,
     theme: &'a dyn Theme,
     paged: bool,
-    page_size: u32,
+    cursor_char: Option<char>,
+    mouse_scroll: bool,
+    vim_mode: bool,
+    select_all_key: char,
+    deselect_all_key: char,
+    escape_returns_current: bool,
+    env_var: Option<String>,
+    horizontal: bool,
+    delimiter: String,
+    page_size: usize,
+    fuzzy: bool,
+    min_selections: Option<usize>,
+    max_selections: Option<usize>,
+    values: Vec<Option<V>>,
+    select_all_item: Option<String>,
+    preview: Option<String>,
+    preview_height: usize,
+}
+
+impl<'a, V: Clone> Clone for MultiSelect<'a, V> {
+    /// Clones the prompt.
+    ///
+    /// Items added with [items_from_iter](#method.items_from_iter) that were
+    /// not fetched yet are loaded first, so both prompts list all items.
+    fn clone(&self) -> MultiSelect<'a, V> {
+        self.load_items(usize::MAX);
+        self.with_items(0, self.items.borrow().len())
+    }
+}
+
+impl<'a, V: Clone> MultiSelect<'a, V> {
+    /// Returns a prompt with the same settings listing the items from
+    /// `start` up to `end`.
+    ///
+    /// Only the loaded items are listed, lazily added ones are left out.
+    fn with_items(&self, start: usize, end: usize) -> MultiSelect<'a, V> {
+        MultiSelect {
+            defaults: slice_of(&self.defaults, start, end),
+            explicit_defaults: self
+                .explicit_defaults
+                .as_ref()
+                .map(|defaults| slice_of(defaults, start, end)),
+            items: RefCell::new(slice_of(&self.items.borrow(), start, end)),
+            source: RefCell::new(None),
+            #[cfg(any(feature = "tokio", feature = "async-std"))]
+            loader: self.loader.clone(),
+            descriptions: slice_of(&self.descriptions, start, end),
+            disabled: slice_of(&self.disabled, start, end),
+            prompt: self.prompt.clone(),
+            clear: self.clear,
+            theme: self.theme,
+            paged: self.paged,
+            cursor_char: self.cursor_char,
+            mouse_scroll: self.mouse_scroll,
+            vim_mode: self.vim_mode,
+            select_all_key: self.select_all_key,
+            deselect_all_key: self.deselect_all_key,
+            escape_returns_current: self.escape_returns_current,
+            env_var: self.env_var.clone(),
+            horizontal: self.horizontal,
+            delimiter: self.delimiter.clone(),
+            page_size: self.page_size,
+            fuzzy: self.fuzzy,
+            min_selections: self.min_selections,
+            max_selections: self.max_selections,
+            values: slice_of(&self.values, start, end),
+            select_all_item: self.select_all_item.clone(),
+            preview: self.preview.clone(),
+            preview_height: self.preview_height,
+        }
+    }
 }
 
 impl<'a> Default for MultiSelect<'a> {
@@ -36,82 +161,416 @@ impl<'a> Default for MultiSelect<'a> {
 
 impl<'a> MultiSelect<'a> {
     /// Creates a multi select prompt.
-    pub fn new() -> MultiSelect<'static> {
-        MultiSelect::with_theme(&SimpleTheme)
+    pub fn new() -> MultiSelect<'a> {
+        MultiSelect::with_theme(default_theme())
     }
 
     /// Creates a multi select prompt with a specific theme.
     pub fn with_theme(theme: &'a dyn Theme) -> MultiSelect<'a> {
+        MultiSelect::valued_with_theme(theme)
+    }
+}
+
+impl<'a, V> MultiSelect<'a, V> {
+    /// Creates a multi select prompt whose items carry values of type `V`.
+    ///
+    /// The values are added with [items_with_values](#method.items_with_values).
+    pub fn valued() -> MultiSelect<'a, V> {
+        MultiSelect::valued_with_theme(default_theme())
+    }
+
+    /// Creates a multi select prompt whose items carry values with a specific theme.
+    pub fn valued_with_theme(theme: &'a dyn Theme) -> MultiSelect<'a, V> {
         MultiSelect {
-            items: vec![],
+            items: RefCell::new(vec![]),
+            source: RefCell::new(None),
+            #[cfg(any(feature = "tokio", feature = "async-std"))]
+            loader: None,
+            descriptions: vec![],
+            disabled: vec![],
             defaults: vec![],
+            explicit_defaults: None,
             clear: true,
             prompt: None,
             theme,
             paged: false,
+            cursor_char: None,
+            mouse_scroll: false,
+            vim_mode: false,
+            select_all_key: '\x01',
+            deselect_all_key: '\x0e',
+            escape_returns_current: false,
+            env_var: None,
+            horizontal: false,
+            delimiter: "  ".into(),
             page_size: 10,
+            fuzzy: false,
+            min_selections: None,
+            max_selections: None,
+            values: vec![],
+            select_all_item: None,
+            preview: None,
+            preview_height: 10,
         }
     }
 
     /// Enables or disables paging
-    pub fn paged(&mut self, val: bool) -> &mut MultiSelect<'a> {
+    pub fn paged(&mut self, val: bool) -> &mut MultiSelect<'a, V> {
         self.paged = val;
         self
     }
 
     /// Declares the page size for the element
-    pub fn page_size(&mut self, val: u32) -> &mut MultiSelect<'a> {
-        self.page_size = if val <= 0 { 10 } else { val };
+    ///
+    /// A page size of `0` fits the page to the height of the terminal,
+    /// see [page_size_auto](#method.page_size_auto).
+    pub fn page_size(&mut self, val: usize) -> &mut MultiSelect<'a, V> {
+        self.page_size = val;
+        self
+    }
+
+    /// Fits the page size to the height of the terminal.
+    ///
+    /// The height is checked every time the prompt is rendered, so resizing
+    /// the terminal takes effect on the next key press.
+    pub fn page_size_auto(&mut self) -> &mut MultiSelect<'a, V> {
+        self.page_size(0)
+    }
+
+    /// Enables or disables fuzzy searching of the items.
+    ///
+    /// When enabled the typed search string only needs to appear as a
+    /// subsequence of an item, and better matches are listed first.
+    /// The default is a case-insensitive substring search.
+    pub fn fuzzy(&mut self, val: bool) -> &mut MultiSelect<'a, V> {
+        self.fuzzy = val;
+        self
+    }
+
+    /// Sets the minimum number of items that have to be checked.
+    ///
+    /// The prompt refuses to confirm with fewer checked items and shows an error instead.
+    pub fn min_selections(&mut self, val: usize) -> &mut MultiSelect<'a, V> {
+        self.min_selections = Some(val);
+        self
+    }
+
+    /// Sets the maximum number of items that can be checked.
+    ///
+    /// The prompt refuses to confirm with more checked items and shows an error instead.
+    pub fn max_selections(&mut self, val: usize) -> &mut MultiSelect<'a, V> {
+        self.max_selections = Some(val);
+        self
+    }
+
+    /// Enables or disables vim-style navigation.
+    ///
+    /// In vim mode `j` and `k` move the selection down and up, `g` jumps to the
+    /// first and `G` to the last item. As letters are used for navigation,
+    /// searching is not available in vim mode.
+    ///
+    /// Vim mode is disabled by default.
+    pub fn vim_mode(&mut self, val: bool) -> &mut MultiSelect<'a, V> {
+        self.vim_mode = val;
+        self
+    }
+
+    /// Renders the items side by side on one line.
+    ///
+    /// Saves vertical space for short lists. The left and right arrows move
+    /// the selection and the items wrap to more lines if they do not fit the
+    /// width of the terminal. Paging and descriptions do not apply in
+    /// horizontal mode.
+    ///
+    /// The default is to render one item per line.
+    pub fn horizontal(&mut self, val: bool) -> &mut MultiSelect<'a, V> {
+        self.horizontal = val;
+        self
+    }
+
+    /// Sets the text between items in [horizontal](#method.horizontal) mode.
+    ///
+    /// The default is two spaces.
+    pub fn horizontal_delimiter<S: Into<String>>(&mut self, val: S) -> &mut MultiSelect<'a, V> {
+        self.delimiter = val.into();
+        self
+    }
+
+    /// Sets the key that checks all visible items.
+    ///
+    /// While searching only the items matching the search are checked. If all
+    /// of them are checked already, pressing the key unchecks them instead.
+    /// The key is not added to the search string, so setting a printable key
+    /// keeps it from being searched for.
+    ///
+    /// The default is Ctrl+A, which terminals report as `'\x01'`, rather than
+    /// `'a'`, which could then not be typed into the search.
+    pub fn select_all_key(&mut self, val: char) -> &mut MultiSelect<'a, V> {
+        self.select_all_key = val;
+        self
+    }
+
+    /// Sets the key that unchecks all visible items.
+    ///
+    /// While searching only the items matching the search are unchecked.
+    /// The key is not added to the search string.
+    ///
+    /// The default is Ctrl+N, which terminals report as `'\x0e'`, rather than
+    /// `'n'`, which could then not be typed into the search.
+    pub fn deselect_all_key(&mut self, val: char) -> &mut MultiSelect<'a, V> {
+        self.deselect_all_key = val;
+        self
+    }
+
+    /// Sets what pressing 'Esc' returns from [interact](#method.interact) and
+    /// [interact_on](#method.interact_on).
+    ///
+    /// By default 'Esc' reverts to the defaults and returns them, discarding
+    /// the items the user checked. When enabled 'Esc' behaves like 'Enter'
+    /// and returns the items checked at that moment.
+    ///
+    /// The cancellable [interact_opt](#method.interact_opt) variants always
+    /// return `None` on 'Esc'.
+    pub fn escape_returns_current(&mut self, val: bool) -> &mut MultiSelect<'a, V> {
+        self.escape_returns_current = val;
+        self
+    }
+
+    /// Reads the answer from the environment variable `name` instead of the terminal.
+    ///
+    /// When the variable is set to a non-empty value the prompt is not shown.
+    /// The value is a comma separated list of the checked items, each given
+    /// by its text or index. Disabled items and lists violating the selection
+    /// limits result in an error of kind `InvalidInput`.
+    ///
+    /// This allows running the prompt non-interactively, e.g. in CI.
+    pub fn env_var<S: Into<String>>(&mut self, name: S) -> &mut MultiSelect<'a, V> {
+        self.env_var = Some(name.into());
+        self
+    }
+
+    /// Enables or disables moving the selection with the mouse wheel.
+    ///
+    /// While the prompt is shown the terminal reports mouse events to it, which
+    /// usually prevents selecting text with the mouse. Terminals that do not
+    /// support mouse reporting keep working with the keyboard only.
+    ///
+    /// Mouse scrolling is disabled by default.
+    pub fn mouse_scroll(&mut self, val: bool) -> &mut MultiSelect<'a, V> {
+        self.mouse_scroll = val;
+        self
+    }
+
+    /// Overrides the cursor glyph shown next to the active item.
+    ///
+    /// By default the glyph is chosen by the theme.
+    pub fn cursor_char(&mut self, val: char) -> &mut MultiSelect<'a, V> {
+        self.cursor_char = Some(val);
         self
     }
 
     /// Sets the clear behavior of the menu.
     ///
     /// The default is to clear the menu.
-    pub fn clear(&mut self, val: bool) -> &mut MultiSelect<'a> {
+    pub fn clear(&mut self, val: bool) -> &mut MultiSelect<'a, V> {
         self.clear = val;
         self
     }
 
     /// Sets a defaults for the menu.
-    pub fn defaults(&mut self, val: &[bool]) -> &mut MultiSelect<'a> {
-        self.defaults = val
-            .to_vec()
-            .iter()
-            .cloned()
-            .chain(repeat(false))
-            .take(self.items.len())
-            .collect();
+    ///
+    /// The defaults can be set before or after the items are added, but
+    /// there has to be exactly one default per item. Otherwise interacting
+    /// with the prompt fails with an error of kind `InvalidInput`.
+    ///
+    /// The defaults override checked states given to
+    /// [item_checked](#method.item_checked) and [items_checked](#method.items_checked).
+    pub fn defaults(&mut self, val: &[bool]) -> &mut MultiSelect<'a, V> {
+        self.explicit_defaults = Some(val.to_vec());
+        self
+    }
+
+    /// Shows an item labeled `label` above all other items that checks or
+    /// unchecks all of them.
+    ///
+    /// The item is checked while all other items are. Checking it checks
+    /// every item that is not disabled, unchecking it unchecks them. While
+    /// searching it only affects the items matching the search. The item is
+    /// not part of the result, the returned indices are those of the other
+    /// items.
+    pub fn with_select_all_item<T: ToString>(&mut self, label: T) -> &mut MultiSelect<'a, V> {
+        self.select_all_item = Some(label.to_string());
+        self
+    }
+
+    /// Shows the output of `cmd` for the highlighted item below the items.
+    ///
+    /// The first word of `cmd` is the program to run, the other words are
+    /// passed to it as arguments followed by the text of the highlighted item,
+    /// e.g. `cat -n` for a list of files. The command is not run by a shell.
+    /// It runs again whenever another item is highlighted and the first
+    /// [preview_height](#method.preview_height) lines of its output are
+    /// shown.
+    pub fn preview(&mut self, cmd: &str) -> &mut MultiSelect<'a, V> {
+        self.preview = Some(cmd.into());
+        self
+    }
+
+    /// Sets the number of lines of the preview.
+    ///
+    /// The default is 10.
+    pub fn preview_height(&mut self, val: usize) -> &mut MultiSelect<'a, V> {
+        self.preview_height = val;
         self
     }
 
     /// Add a single item to the selector.
     #[inline]
-    pub fn item<T: ToString>(&mut self, item: T) -> &mut MultiSelect<'a> {
+    pub fn item<T: ToString>(&mut self, item: T) -> &mut MultiSelect<'a, V> {
         self.item_checked(item, false)
     }
 
     /// Add a single item to the selector with a default checked state.
-    pub fn item_checked<T: ToString>(&mut self, item: T, checked: bool) -> &mut MultiSelect<'a> {
-        self.items.push(item.to_string());
+    pub fn item_checked<T: ToString>(&mut self, item: T, checked: bool) -> &mut MultiSelect<'a, V> {
+        self.items.get_mut().push(item.to_string());
+        self.descriptions.push(None);
+        self.disabled.push(false);
         self.defaults.push(checked);
         self
     }
 
+    /// Add a single item to the selector that cannot be checked.
+    ///
+    /// Disabled items can still be highlighted, pressing space has no effect on them.
+    pub fn item_disabled<T: ToString>(&mut self, item: T) -> &mut MultiSelect<'a, V> {
+        self.items.get_mut().push(item.to_string());
+        self.descriptions.push(None);
+        self.disabled.push(true);
+        self.defaults.push(false);
+        self
+    }
+
+    /// Add a single item to the selector with a description rendered below it.
+    pub fn item_with_description<T: ToString, D: ToString>(
+        &mut self,
+        item: T,
+        desc: D,
+    ) -> &mut MultiSelect<'a, V> {
+        self.items.get_mut().push(item.to_string());
+        self.descriptions.push(Some(desc.to_string()));
+        self.disabled.push(false);
+        self.defaults.push(false);
+        self
+    }
+
     /// Adds multiple items to the selector.
-    pub fn items<T: ToString>(&mut self, items: &[T]) -> &mut MultiSelect<'a> {
+    ///
+    /// Accepts slices as well as any other iterable of items.
+    pub fn items<T, I>(&mut self, items: I) -> &mut MultiSelect<'a, V>
+    where
+        T: ToString,
+        I: IntoIterator<Item = T>,
+    {
         for item in items {
-            self.items.push(item.to_string());
-            self.defaults.push(false);
+            self.item_checked(item, false);
         }
         self
     }
 
+    /// Adds items to the selector that are only pulled from `iter` when needed.
+    ///
+    /// In paged mode items are fetched a page at a time as the user navigates
+    /// to them, which avoids collecting large data sources upfront. Searching or
+    /// disabling paging fetches all remaining items.
+    ///
+    /// These items are listed after all other items and are unchecked by
+    /// default, [defaults](#method.defaults) does not cover them. Cloning the
+    /// prompt fetches all remaining items.
+    pub fn items_from_iter<T, I>(&mut self, iter: I) -> &mut MultiSelect<'a, V>
+    where
+        T: ToString,
+        I: Iterator<Item = T> + 'a,
+    {
+        let iter = iter.map(|item| item.to_string());
+        let source = self.source.get_mut();
+
+        *source = Some(match source.take() {
+            Some(prev) => Box::new(prev.chain(iter)),
+            None => Box::new(iter),
+        });
+        self
+    }
+
+    /// Sets an async callback that loads the items matching the search.
+    ///
+    /// The loader is only used by [interact_async](#method.interact_async). It
+    /// is called with an empty query when the interaction starts and with the
+    /// search string whenever it changes. Returned items that are not listed
+    /// yet are appended unchecked, so returned indices refer to all items in
+    /// the order they were added or loaded.
+    ///
+    /// Loaded items are still filtered by the search string, enable
+    /// [fuzzy](#method.fuzzy) if the loader matches fuzzily.
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    pub fn items_loader<F, Fut>(&mut self, loader: F) -> &mut MultiSelect<'a, V>
+    where
+        F: Fn(&str) -> Fut + 'a,
+        Fut: Future<Output = Vec<String>> + 'a,
+    {
+        self.loader = Some(Rc::new(move |query| Box::pin(loader(query))));
+        self
+    }
+
     /// Adds multiple items to the selector with checked state
-    pub fn items_checked<T: ToString>(&mut self, items: &[(T, bool)]) -> &mut MultiSelect<'a> {
-        for &(ref item, checked) in items {
-            self.items.push(item.to_string());
-            self.defaults.push(checked);
+    ///
+    /// Accepts slices of `(item, checked)` pairs as well as any iterable
+    /// yielding them by value.
+    pub fn items_checked<T, B, I>(&mut self, items: I) -> &mut MultiSelect<'a, V>
+    where
+        T: ToString,
+        B: Borrow<(T, bool)>,
+        I: IntoIterator<Item = B>,
+    {
+        for pair in items {
+            let (ref item, checked) = *pair.borrow();
+            self.item_checked(item.to_string(), checked);
+        }
+        self
+    }
+
+    /// Adds multiple items to the selector with descriptions
+    ///
+    /// Accepts slices of `(item, description)` pairs as well as any iterable
+    /// yielding them by value.
+    pub fn items_with_descriptions<T, D, B, I>(&mut self, items: I) -> &mut MultiSelect<'a, V>
+    where
+        T: ToString,
+        D: ToString,
+        B: Borrow<(T, D)>,
+        I: IntoIterator<Item = B>,
+    {
+        for pair in items {
+            let (ref item, ref desc) = *pair.borrow();
+            self.item_with_description(item.to_string(), desc.to_string());
+        }
+        self
+    }
+
+    /// Adds multiple items to the selector, each carrying a value.
+    ///
+    /// The values of the checked items are returned by
+    /// [interact_values](#method.interact_values).
+    pub fn items_with_values<T, I>(&mut self, items: I) -> &mut MultiSelect<'a, V>
+    where
+        T: ToString,
+        I: IntoIterator<Item = (T, V)>,
+    {
+        for (item, value) in items {
+            self.item(item.to_string());
+
+            let idx = self.items.get_mut().len() - 1;
+            self.values.resize_with(idx, || None);
+            self.values.push(Some(value));
         }
         self
     }
@@ -119,8 +578,9 @@ impl<'a> MultiSelect<'a> {
     /// Prefaces the menu with a prompt.
     ///
     /// When a prompt is set the system also prints out a confirmation after
-    /// the selection.
-    pub fn with_prompt<S: Into<String>>(&mut self, prompt: S) -> &mut MultiSelect<'a> {
+    /// the selection. The prompt may span several lines separated by `\n`,
+    /// e.g. to show instructions above the items.
+    pub fn with_prompt<S: Into<String>>(&mut self, prompt: S) -> &mut MultiSelect<'a, V> {
         self.prompt = Some(prompt.into());
         self
     }
@@ -133,197 +593,1426 @@ impl<'a> MultiSelect<'a> {
         self.interact_on(&Term::stderr())
     }
 
+    /// Enables user interaction and returns the result.
+    ///
+    /// This method is similar to [interact_on_opt](#method.interact_on_opt) except for the fact that it does not allow selection of the terminal.
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(Vec<index>)` if user hit 'Enter' or `None` if user cancelled with 'Esc'.
+    pub fn interact_opt(&self) -> io::Result<Option<Vec<usize>>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
     /// Like [interact](#method.interact) but allows a specific terminal to be set.
+    ///
+    /// Pressing 'Esc' restores the defaults and returns them, unless
+    /// [escape_returns_current](#method.escape_returns_current) is set.
     pub fn interact_on(&self, term: &Term) -> io::Result<Vec<usize>> {
-        let mut page = 0;
+        self.interact_on_with_io(term, term)
+    }
+
+    /// Like [interact_on](#method.interact_on) but reads keys from `input` and renders on `output`.
+    pub fn interact_on_with_io(&self, input: &Term, output: &Term) -> io::Result<Vec<usize>> {
+        self._interact_on(input, output, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Enables user interaction and returns the checked items themselves.
+    ///
+    /// Like [interact](#method.interact) but maps the selected indices back to the item texts.
+    pub fn interact_text(&self) -> io::Result<Vec<String>> {
+        self.interact_text_on(&Term::stderr())
+    }
+
+    /// Like [interact_text](#method.interact_text) but allows a specific terminal to be set.
+    pub fn interact_text_on(&self, term: &Term) -> io::Result<Vec<String>> {
+        Ok(self
+            .interact_on(term)?
+            .into_iter()
+            .map(|idx| self.items.borrow()[idx].clone())
+            .collect())
+    }
+
+    /// Enables user interaction and returns every item mapped to whether it is checked.
+    ///
+    /// Items are keyed by the texts they were added with, regardless of any
+    /// search the user typed. Items sharing a text are merged into one entry,
+    /// which is checked if any of them is.
+    pub fn interact_map(&self) -> io::Result<HashMap<String, bool>> {
+        self.interact_map_on(&Term::stderr())
+    }
+
+    /// Like [interact_map](#method.interact_map) but allows a specific terminal to be set.
+    pub fn interact_map_on(&self, term: &Term) -> io::Result<HashMap<String, bool>> {
+        let selected = self.interact_on(term)?;
+        Ok(self.checked_map(&selected))
+    }
+
+    /// Enables user interaction and returns the values of the checked items.
+    ///
+    /// Checked items that were added without a value are left out, see
+    /// [items_with_values](#method.items_with_values).
+    pub fn interact_values(&self) -> io::Result<Vec<&V>> {
+        self.interact_values_on(&Term::stderr())
+    }
+
+    /// Like [interact_values](#method.interact_values) but allows a specific terminal to be set.
+    pub fn interact_values_on(&self, term: &Term) -> io::Result<Vec<&V>> {
+        let selected = self.interact_on(term)?;
+        Ok(self.checked_values(&selected))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    ///
+    /// Unlike [interact_on](#method.interact_on) pressing 'Esc' does not restore
+    /// the defaults, so it can be told apart from confirming with 'Enter'.
+    ///
+    /// ## Examples
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    /// use dialoguer::console::Term;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let selections = MultiSelect::new()
+    ///         .item("Option A")
+    ///         .item("Option B")
+    ///         .interact_on_opt(&Term::stderr())?;
+    ///
+    ///     match selections {
+    ///         Some(positions) => println!("User selected options at {:?}", positions),
+    ///         None => println!("User cancelled the selection")
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<Vec<usize>>> {
+        self._interact_on(term, term, true)
+    }
+
+    /// Like [interact_on_opt](#method.interact_on_opt) but reads keys from `input` and renders on `output`.
+    #[inline]
+    pub fn interact_on_opt_with_io(
+        &self,
+        input: &Term,
+        output: &Term,
+    ) -> io::Result<Option<Vec<usize>>> {
+        self._interact_on(input, output, true)
+    }
+
+    /// Runs the prompt on the given key presses instead of reading them from a terminal.
+    ///
+    /// The prompt is rendered into a buffer rather than on the terminal, which
+    /// allows testing the interaction without a TTY. Like with
+    /// [interact](#method.interact) 'Esc' restores the defaults. If the keys run
+    /// out before the prompt is done an error of kind `UnexpectedEof` is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use dialoguer::{console::Key, MultiSelect};
+    ///
+    /// let chosen = MultiSelect::new()
+    ///     .items(&["Option 1", "Option 2"])
+    ///     .interact_with_keys(vec![Key::ArrowDown, Key::Char(' '), Key::Enter])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(chosen, vec![1]);
+    /// ```
+    pub fn interact_with_keys<I>(&self, keys: I) -> io::Result<Vec<usize>>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, self.theme);
+        render.capture_output();
+
+        self.run(&mut ScriptedKeys(keys.into_iter()), &mut render, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact](#method.interact) but waits for key presses without
+    /// blocking the async runtime.
+    ///
+    /// Keys are read on a blocking thread of the `tokio` or `async-std`
+    /// runtime, depending on the enabled feature. Items from an
+    /// [items_loader](#method.items_loader) are fetched as the user searches.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use dialoguer::MultiSelect;
+    ///
+    /// async fn fetch_packages(query: String) -> Vec<String> {
+    ///     // Query a remote API
+    ///     # vec![query]
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> std::io::Result<()> {
+    ///     let chosen = MultiSelect::new()
+    ///         .with_prompt("Packages to install")
+    ///         .items_loader(|query| fetch_packages(query.to_string()))
+    ///         .interact_text_async()
+    ///         .await?;
+    ///
+    ///     println!("Installing {:?}", chosen);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    pub async fn interact_async(&self) -> io::Result<Vec<usize>> {
+        self.interact_async_on(&Term::stderr()).await
+    }
+
+    /// Like [interact_async](#method.interact_async) but allows a specific terminal to be set.
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    pub async fn interact_async_on(&self, term: &Term) -> io::Result<Vec<usize>> {
+        if let Some(ref var) = self.env_var {
+            if let Some(value) = env::answer(Some(var)) {
+                self.load_matching("").await;
+                return self.env_answer(var, &value);
+            }
+        }
+
+        let _mouse = MouseCapture::new(term, self.mouse_scroll)?;
+        let _cursor = CursorGuard::new(term);
+        let mut render = TermThemeRenderer::new(term, self.theme);
+
+        self.load_matching("").await;
+
+        let mut state = self.start(&mut render)?;
 
-        if self.items.is_empty() {
+        loop {
+            self.render_state(&mut state, &mut render)?;
+            let search_string = state.search_string.clone();
+            let key = read_key_async(term).await?;
+
+            if let Some(result) = self.handle_key(&mut state, key, &mut render, false)? {
+                return result.ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case")
+                });
+            }
+
+            if state.search_string != search_string {
+                self.load_matching(&state.search_string).await;
+            }
+
+            render.redraw()?;
+        }
+    }
+
+    /// Like [interact_async](#method.interact_async) but maps the selected
+    /// indices back to the item texts.
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    pub async fn interact_text_async(&self) -> io::Result<Vec<String>> {
+        let selected = self.interact_async().await?;
+        let items = self.items.borrow();
+
+        Ok(selected.into_iter().map(|idx| items[idx].clone()).collect())
+    }
+
+    fn _interact_on(
+        &self,
+        mut input: &Term,
+        term: &Term,
+        allow_quit: bool,
+    ) -> io::Result<Option<Vec<usize>>> {
+        if let Some(ref var) = self.env_var {
+            if let Some(value) = env::answer(Some(var)) {
+                return self.env_answer(var, &value).map(Some);
+            }
+        }
+
+        let _mouse = MouseCapture::new(term, self.mouse_scroll)?;
+        let _cursor = CursorGuard::new(term);
+        let mut render = TermThemeRenderer::new(term, self.theme);
+
+        self.run(&mut input, &mut render, allow_quit)
+    }
+
+    /// The interaction loop shared by the terminal and the scripted interaction.
+    fn run(
+        &self,
+        input: &mut dyn KeySource,
+        render: &mut TermThemeRenderer,
+        allow_quit: bool,
+    ) -> io::Result<Option<Vec<usize>>> {
+        let mut state = self.start(render)?;
+
+        loop {
+            self.render_state(&mut state, render)?;
+
+            if let Some(result) =
+                self.handle_key(&mut state, input.read_key()?, render, allow_quit)?
+            {
+                return Ok(result);
+            }
+
+            render.redraw()?;
+        }
+    }
+
+    /// Checks the items and prepares the state of a new interaction.
+    fn start(&self, render: &mut TermThemeRenderer) -> io::Result<State> {
+        self.load_items(1);
+
+        if self.items.borrow().is_empty() {
             return Err(io::Error::new(
                 io::ErrorKind::Other,
                 "Empty list of items given to `MultiSelect`",
             ));
         }
 
-        let capacity = if self.paged {
-            if self.page_size > 0 {
-                self.page_size as usize
-            } else {
-                10 as usize
-            }
-        } else {
-            self.items.len()
-        };
+        render.set_cursor_char(self.cursor_char);
 
-        let pages = (self.items.len() as f64 / capacity as f64).ceil() as usize;
+        let defaults = self.checked_defaults()?;
 
-        let mut render = TermThemeRenderer::new(term, self.theme);
-        let mut sel = 0;
-        let mut prompt_string: String = String::from("");
+        Ok(State {
+            page: 0,
+            sel: 0,
+            checked: defaults.clone(),
+            defaults,
+            search_string: String::new(),
+            show_error: false,
+            preview: None,
+        })
+    }
+
+    /// Renders the prompt and the current page of items.
+    fn render_state(&self, state: &mut State, render: &mut TermThemeRenderer) -> io::Result<()> {
+        let term = render.term();
 
-        if let Some(ref prompt) = self.prompt {
-            prompt_string = String::from(prompt);
-            // render.multi_select_prompt(prompt)?;
+        // Fetch lazily added items up to one past the current page, so it
+        // is known whether there is a next page.
+        if !self.paged || !state.search_string.is_empty() {
+            self.load_items(usize::MAX);
+        } else {
+            self.load_items(
+                (state.page + 1)
+                    .saturating_mul(self.capacity(term))
+                    .saturating_add(1),
+            );
         }
 
-        let mut size_vec = Vec::new();
+        let items = self.items.borrow();
+        state.checked.resize(items.len(), false);
 
-        for items in self
-            .items
-            .iter()
-            .flat_map(|i| i.split('\n'))
-            .collect::<Vec<_>>()
-        {
-            let size = &items.len();
-            size_vec.push(*size);
+        // Recomputed on every render so resizing the terminal takes effect
+        let capacity = self.capacity(term);
+        let filtered_items = self.entries(&items, &state.search_string);
+
+        // The filter may have shrunk the list below the highlighted position.
+        state.sel = clamp_selection(state.sel, filtered_items.len());
+
+        // Pages are derived from the filtered list so the indicator stays
+        // accurate while the user is searching.
+        let pages = page_count(filtered_items.len(), capacity);
+
+        if state.page >= pages {
+            state.page = pages - 1;
         }
 
-        let mut checked: Vec<bool> = self.defaults.clone();
-        let mut search_string: String = String::from("");
-        let original_items = self.items.clone();
+        // The search is part of every render, so it stays visible on every page
+        let render_prompt_str = self.prompt_with_search(&state.search_string);
+        render.multi_select_prompt(
+            &render_prompt_str,
+            if self.paged && pages > 1 {
+                Some((state.page, pages))
+            } else {
+                None
+            },
+        )?;
 
-        loop {
-            let render_prompt_str = format!("{} {}", prompt_string, search_string);
-            render.clear()?;
-            render.multi_select_prompt(&render_prompt_str)?;
-            let filtered_indexed_items: Vec<_> = original_items
+        if self.horizontal {
+            let items: Vec<_> = filtered_items
                 .iter()
                 .enumerate()
-                .filter(|&(_, item)| {
-                    search_string.len() == 0
-                        || item.to_lowercase().contains(&search_string.to_lowercase())
+                .map(|(idx, &(item, orig_idx))| {
+                    (
+                        item.as_str(),
+                        self.is_checked(&state.checked, &filtered_items, orig_idx),
+                        state.sel == idx,
+                        self.is_disabled(orig_idx),
+                    )
                 })
-                .map(|(idx, item)| (item, idx))
                 .collect();
 
-            let filtered_items: Vec<_> = filtered_indexed_items
-                .iter()
-                .map(|(item, _)| item)
-                .collect();
-
-            for (idx, item) in filtered_items
+            render.multi_select_prompt_items_horizontal(&items, &self.delimiter)?;
+        } else {
+            for (idx, &(item, orig_idx)) in filtered_items
                 .iter()
                 .enumerate()
-                .skip(page * capacity)
+                .skip(state.page * capacity)
                 .take(capacity)
             {
                 // Render the prompt and selected text if it exists
-                let (_, orig_idx) = filtered_indexed_items[idx];
-                render.multi_select_prompt_item(item, checked[orig_idx], sel == idx)?;
-            }
-
-            term.hide_cursor()?;
-            term.flush()?;
+                let checked = self.is_checked(&state.checked, &filtered_items, orig_idx);
 
-            match term.read_key()? {
-                Key::ArrowDown => {
-                    if sel == !0 {
-                        sel = 0;
-                    } else {
-                        sel = (sel as u64 + 1).rem(filtered_items.len() as u64) as usize;
-                    }
+                if self.is_disabled(orig_idx) {
+                    render.multi_select_prompt_item_disabled(item, checked, state.sel == idx)?;
+                } else {
+                    render.multi_select_prompt_item(item, checked, state.sel == idx)?;
                 }
-                Key::ArrowUp => {
-                    if sel == !0 {
-                        sel = filtered_items.len() - 1;
-                    } else {
-                        sel = ((sel as i64 - 1 + filtered_items.len() as i64)
-                            % (filtered_items.len() as i64)) as usize;
-                    }
-                }
-                Key::ArrowLeft => {
-                    if self.paged {
-                        if page == 0 {
-                            page = pages - 1;
-                        } else {
-                            page -= 1;
-                        }
-
-                        sel = page * capacity;
-                    }
+
+                if let Some(Some(ref desc)) = self.descriptions.get(orig_idx) {
+                    render.multi_select_prompt_item_desc(desc)?;
                 }
-                Key::ArrowRight => {
-                    if self.paged {
-                        if page == pages - 1 {
-                            page = 0;
-                        } else {
-                            page += 1;
-                        }
-
-                        sel = page * capacity;
+            }
+        }
+
+        if let Some(ref cmd) = self.preview {
+            match filtered_items.get(state.sel) {
+                Some(&(item, orig_idx)) if orig_idx != SELECT_ALL => {
+                    if state.preview.as_ref().map(|&(idx, _)| idx) != Some(orig_idx) {
+                        let lines = run_preview(cmd, item, self.preview_height);
+                        state.preview = Some((orig_idx, lines));
                     }
                 }
-                Key::Char(' ') => {
-                    // TODO: Fetch the original index from the items list
-                    // and add update the checked array entries
-                    let (_, orig_idx) = filtered_indexed_items[sel];
-                    checked[orig_idx] = !checked[orig_idx];
-                }
-                Key::Escape => {
-                    if self.clear {
-                        render.clear()?;
-                    }
+                _ => state.preview = None,
+            }
 
-                    if let Some(ref prompt) = self.prompt {
-                        render.multi_select_prompt_selection(prompt, &[][..])?;
-                    }
+            if let Some((_, ref lines)) = state.preview {
+                render.preview(lines)?;
+            }
+        }
+
+        if state.show_error {
+            if let Some(err) = self.selection_count_error(&state.checked) {
+                render.multi_select_prompt_error(&err)?;
+            } else {
+                state.show_error = false;
+            }
+        }
 
-                    term.show_cursor()?;
-                    term.flush()?;
+        render.hide_cursor()?;
+        render.flush()
+    }
 
-                    return Ok(self
-                        .defaults
-                        .clone()
-                        .into_iter()
-                        .enumerate()
-                        .filter_map(|(idx, checked)| if checked { Some(idx) } else { None })
-                        .collect());
+    /// Applies a key press to the state.
+    ///
+    /// Returns `Some` with the result of the interaction once the user is done.
+    fn handle_key(
+        &self,
+        state: &mut State,
+        key: Key,
+        render: &mut TermThemeRenderer,
+        allow_quit: bool,
+    ) -> io::Result<Option<Option<Vec<usize>>>> {
+        let key = match key {
+            Key::Char('j') if self.vim_mode => Key::ArrowDown,
+            Key::Char('k') if self.vim_mode => Key::ArrowUp,
+            Key::Char('g') if self.vim_mode => Key::Home,
+            Key::Char('G') if self.vim_mode => Key::End,
+            Key::ArrowLeft if self.horizontal => Key::ArrowUp,
+            Key::ArrowRight if self.horizontal => Key::ArrowDown,
+            Key::Escape if self.escape_returns_current && !allow_quit => Key::Enter,
+            key => key,
+        };
+
+        let items = self.items.borrow();
+        let capacity = self.capacity(render.term());
+        let filtered_items = self.entries(&items, &state.search_string);
+        let pages = page_count(filtered_items.len(), capacity);
+
+        match key {
+            Key::ArrowDown | Key::ArrowUp | Key::Home | Key::End | Key::Char(' ')
+                if filtered_items.is_empty() => {}
+            Key::ArrowDown => {
+                if state.sel == !0 {
+                    state.sel = 0;
+                } else {
+                    state.sel = (state.sel as u64 + 1).rem(filtered_items.len() as u64) as usize;
                 }
-                Key::Enter => {
-                    if self.clear {
-                        render.clear()?;
+            }
+            Key::ArrowUp => {
+                if state.sel == !0 {
+                    state.sel = filtered_items.len() - 1;
+                } else {
+                    state.sel = ((state.sel as i64 - 1 + filtered_items.len() as i64)
+                        % (filtered_items.len() as i64)) as usize;
+                }
+            }
+            Key::Home => {
+                state.sel = 0;
+            }
+            Key::End => {
+                state.sel = filtered_items.len() - 1;
+            }
+            Key::ArrowLeft => {
+                if self.paged {
+                    if state.page == 0 {
+                        state.page = pages - 1;
+                    } else {
+                        state.page -= 1;
                     }
 
-                    if let Some(ref prompt) = self.prompt {
-                        let selections: Vec<_> = checked
-                            .iter()
-                            .enumerate()
-                            .filter_map(|(idx, &checked)| {
-                                if checked {
-                                    Some(self.items[idx].as_str())
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect();
-
-                        render.multi_select_prompt_selection(prompt, &selections[..])?;
+                    state.sel = state.page * capacity;
+                }
+            }
+            Key::ArrowRight => {
+                if self.paged {
+                    if state.page == pages - 1 {
+                        state.page = 0;
+                    } else {
+                        state.page += 1;
                     }
 
-                    term.show_cursor()?;
-                    term.flush()?;
+                    state.sel = state.page * capacity;
+                }
+            }
+            Key::Char(' ') => {
+                let (_, orig_idx) = filtered_items[state.sel];
+
+                if orig_idx == SELECT_ALL {
+                    let all_checked = self.is_checked(&state.checked, &filtered_items, orig_idx);
+                    self.set_checked(&mut state.checked, &filtered_items, !all_checked);
+                } else if !self.is_disabled(orig_idx) {
+                    state.checked[orig_idx] = !state.checked[orig_idx];
+                }
+            }
+            Key::Escape => {
+                if self.clear {
+                    render.clear()?;
+                }
 
-                    return Ok(checked
+                if allow_quit {
+                    render.show_cursor()?;
+                    render.flush()?;
+
+                    return Ok(Some(None));
+                }
+
+                if let Some(ref prompt) = self.prompt {
+                    let selections: Vec<_> = checked_indices(&state.defaults)
                         .into_iter()
-                        .enumerate()
-                        .filter_map(|(idx, checked)| if checked { Some(idx) } else { None })
-                        .collect());
+                        .map(|idx| items[idx].as_str())
+                        .collect();
+
+                    render.multi_select_prompt_selection(prompt, &selections[..])?;
                 }
-                Key::Char(x) => {
-                    search_string.push(x);
+
+                render.show_cursor()?;
+                render.flush()?;
+
+                return Ok(Some(Some(checked_indices(&state.defaults))));
+            }
+            Key::Enter if self.selection_count_error(&state.checked).is_some() => {
+                state.show_error = true;
+            }
+            Key::Enter => {
+                if self.clear {
+                    render.clear()?;
                 }
-                Key::Backspace => {
-                    if search_string.len() > 0 {
-                        search_string.pop();
-                    }
+
+                if let Some(ref prompt) = self.prompt {
+                    let selections: Vec<_> = checked_indices(&state.checked)
+                        .into_iter()
+                        .map(|idx| items[idx].as_str())
+                        .collect();
+
+                    render.multi_select_prompt_selection(prompt, &selections[..])?;
                 }
-                _ => {}
+
+                render.show_cursor()?;
+                render.flush()?;
+
+                return Ok(Some(Some(checked_indices(&state.checked))));
             }
+            Key::Char(x) if x == self.select_all_key => {
+                let all_checked = self.is_checked(&state.checked, &filtered_items, SELECT_ALL);
 
-            if sel < page * capacity || sel >= (page + 1) * capacity {
-                page = sel / capacity;
+                self.set_checked(&mut state.checked, &filtered_items, !all_checked);
+            }
+            Key::Char(x) if x == self.deselect_all_key => {
+                self.set_checked(&mut state.checked, &filtered_items, false);
+            }
+            Key::Char(x) if !self.vim_mode && !x.is_control() => {
+                state.search_string.push(x);
+                state.reset_position();
             }
+            Key::Backspace => {
+                if state.search_string.pop().is_some() {
+                    state.reset_position();
+                }
+            }
+            _ => {}
+        }
+
+        if state.sel < state.page * capacity || state.sel >= (state.page + 1) * capacity {
+            state.page = state.sel / capacity;
+        }
+
+        Ok(None)
+    }
+
+    /// Parses the comma separated answer `value` read from `var`.
+    fn env_answer(&self, var: &str, value: &str) -> io::Result<Vec<usize>> {
+        self.load_items(usize::MAX);
+
+        let items = self.items.borrow();
+        let mut checked = vec![false; items.len()];
+
+        for part in value.split(',') {
+            let idx = env::find_item(var, part, items.iter())?;
+
+            if self.is_disabled(idx) {
+                return Err(env::invalid_answer(
+                    var,
+                    &format!("item `{}` is disabled", items[idx]),
+                ));
+            }
+
+            checked[idx] = true;
+        }
 
-            render.clear_preserve_prompt(&size_vec)?;
+        match self.selection_count_error(&checked) {
+            Some(err) => Err(env::invalid_answer(var, &err)),
+            None => Ok(checked_indices(&checked)),
         }
     }
+
+    /// Returns the values of the items in `selected` that carry one.
+    fn checked_values(&self, selected: &[usize]) -> Vec<&V> {
+        selected
+            .iter()
+            .filter_map(|&idx| self.values.get(idx).and_then(Option::as_ref))
+            .collect()
+    }
+
+    /// Maps the text of every item to whether it is among `selected`.
+    fn checked_map(&self, selected: &[usize]) -> HashMap<String, bool> {
+        let mut map = HashMap::new();
+
+        for (idx, item) in self.items.borrow().iter().enumerate() {
+            *map.entry(item.clone()).or_insert(false) |= selected.contains(&idx);
+        }
+
+        map
+    }
+
+    /// Returns the initial checked state of every item.
+    fn checked_defaults(&self) -> io::Result<Vec<bool>> {
+        match self.explicit_defaults {
+            Some(ref defaults) if defaults.len() != self.defaults.len() => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{} defaults given to `MultiSelect` with {} items",
+                    defaults.len(),
+                    self.defaults.len()
+                ),
+            )),
+            Some(ref defaults) => Ok(defaults.clone()),
+            None => Ok(self.defaults.clone()),
+        }
+    }
+
+    /// Returns the number of items if it is known without loading the
+    /// lazily added ones.
+    fn known_len(&self) -> Option<usize> {
+        let len = self.items.borrow().len();
+
+        match *self.source.borrow() {
+            Some(ref iter) => match iter.size_hint() {
+                (lower, Some(upper)) if lower == upper => Some(len + lower),
+                _ => None,
+            },
+            None => Some(len),
+        }
+    }
+
+    /// Pulls lazily added items until `len` items are loaded or there are no more.
+    fn load_items(&self, len: usize) {
+        let mut source = self.source.borrow_mut();
+        let mut items = self.items.borrow_mut();
+
+        if let Some(ref mut iter) = *source {
+            let missing = len.saturating_sub(items.len());
+            items.extend(iter.take(missing));
+        }
+
+        if items.len() < len {
+            *source = None;
+        }
+    }
+
+    /// Appends the items the loader returns for `query` that are not listed yet.
+    #[cfg(any(feature = "tokio", feature = "async-std"))]
+    async fn load_matching(&self, query: &str) {
+        if let Some(ref loader) = self.loader {
+            let loaded = loader(query).await;
+            let mut items = self.items.borrow_mut();
+
+            for item in loaded {
+                if !items.contains(&item) {
+                    items.push(item);
+                }
+            }
+        }
+    }
+
+    /// Sets the checked state of all given items that are not disabled.
+    fn set_checked(&self, checked: &mut [bool], items: &[(&String, usize)], val: bool) {
+        for &(_, idx) in items {
+            if idx != SELECT_ALL && !self.is_disabled(idx) {
+                checked[idx] = val;
+            }
+        }
+    }
+
+    /// Returns whether the item at `idx` is shown as checked.
+    ///
+    /// The select all item is checked while all other `items` that are not
+    /// disabled are.
+    fn is_checked(&self, checked: &[bool], items: &[(&String, usize)], idx: usize) -> bool {
+        if idx == SELECT_ALL {
+            items
+                .iter()
+                .filter(|&&(_, idx)| idx != SELECT_ALL)
+                .all(|&(_, idx)| self.is_disabled(idx) || checked[idx])
+        } else {
+            checked[idx]
+        }
+    }
+
+    /// Returns whether the item at `idx` cannot be checked.
+    fn is_disabled(&self, idx: usize) -> bool {
+        self.disabled.get(idx).copied().unwrap_or(false)
+    }
+
+    /// Returns the prompt followed by the search.
+    fn prompt_with_search(&self, search: &str) -> String {
+        match self.prompt {
+            Some(ref prompt) if !search.is_empty() => {
+                format!("{}{}{}", prompt, self.theme.search_separator(), search)
+            }
+            Some(ref prompt) => prompt.clone(),
+            None => search.into(),
+        }
+    }
+
+    /// Returns the number of items shown at once.
+    fn capacity(&self, term: &Term) -> usize {
+        let len = self.items.borrow().len() + self.select_all_item.is_some() as usize;
+
+        if !self.paged || self.horizontal {
+            return len;
+        }
+
+        let page_size = if self.page_size > 0 {
+            self.page_size
+        } else {
+            // Leave room for the prompt, the preview and the error line
+            let prompt_lines = self
+                .prompt
+                .as_ref()
+                .map_or(1, |prompt| prompt.split('\n').count());
+            let preview_lines = if self.preview.is_some() {
+                self.preview_height
+            } else {
+                0
+            };
+            (term.size().0 as usize).saturating_sub(prompt_lines + preview_lines + 1)
+        };
+
+        // Descriptions take up a line of their own
+        let page_size = if self.descriptions.iter().any(Option::is_some) {
+            page_size / 2
+        } else {
+            page_size
+        };
+
+        // A page never holds more than all items, unless more are still to be fetched
+        if self.source.borrow().is_some() {
+            page_size.max(1)
+        } else {
+            page_size.max(1).min(len)
+        }
+    }
+
+    /// Returns the entries of the list: the select all item, if any, followed
+    /// by the items matching `search`.
+    ///
+    /// The select all item has the index [SELECT_ALL].
+    fn entries<'b>(&'b self, items: &'b [String], search: &str) -> Vec<(&'b String, usize)> {
+        let filtered = self.filter_items(items, search);
+
+        match self.select_all_item {
+            Some(ref label) => Some((label, SELECT_ALL))
+                .into_iter()
+                .chain(filtered)
+                .collect(),
+            None => filtered,
+        }
+    }
+
+    /// Returns the items matching `search` along with their original index.
+    fn filter_items<'b>(&self, items: &'b [String], search: &str) -> Vec<(&'b String, usize)> {
+        if self.fuzzy && !search.is_empty() {
+            let mut scored: Vec<_> = items
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, item)| fuzzy_score(item, search).map(|score| (item, idx, score)))
+                .collect();
+
+            // `sort_by_key` is stable, so equally scored items keep their order.
+            scored.sort_by_key(|&(.., score)| Reverse(score));
+            scored
+                .into_iter()
+                .map(|(item, idx, _)| (item, idx))
+                .collect()
+        } else {
+            let search = search.to_lowercase();
+
+            items
+                .iter()
+                .enumerate()
+                .filter(|&(_, item)| search.is_empty() || item.to_lowercase().contains(&search))
+                .map(|(idx, item)| (item, idx))
+                .collect()
+        }
+    }
+
+    /// Returns an error message if the number of checked items violates
+    /// the configured selection limits.
+    fn selection_count_error(&self, checked: &[bool]) -> Option<String> {
+        let count = checked.iter().filter(|&&checked| checked).count();
+
+        match (self.min_selections, self.max_selections) {
+            (Some(min), _) if count < min => Some(format!(
+                "Select at least {} item{}",
+                min,
+                if min == 1 { "" } else { "s" }
+            )),
+            (_, Some(max)) if count > max => Some(format!(
+                "Select at most {} item{}",
+                max,
+                if max == 1 { "" } else { "s" }
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, V: Clone> MultiSelect<'a, V> {
+    /// Lets the user check items one page at a time.
+    ///
+    /// Every page is a prompt of its own, listing the next
+    /// [page_size](#method.page_size) items. The returned iterator shows the
+    /// next page when advanced and yields the indices of the items checked
+    /// on it once the user confirms. Limits set with
+    /// [min_selections](#method.min_selections) and
+    /// [max_selections](#method.max_selections) apply to every page. Items
+    /// added with [items_from_iter](#method.items_from_iter) are only loaded
+    /// once their page is shown.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let items: Vec<_> = (1..=100).map(|n| format!("Record {}", n)).collect();
+    ///
+    /// for page in MultiSelect::new().items(&items).page_size(20).interact_paginated()? {
+    ///     for idx in page? {
+    ///         println!("Processing {}", items[idx]);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn interact_paginated(&self) -> io::Result<PagedSelections<'_, 'a, V>> {
+        self.interact_paginated_on(&Term::stderr())
+    }
+
+    /// Like [interact_paginated](#method.interact_paginated) but allows a specific terminal to be set.
+    pub fn interact_paginated_on(&self, term: &Term) -> io::Result<PagedSelections<'_, 'a, V>> {
+        let defaults = self.checked_defaults()?;
+        let page_size = match self.page_size {
+            // Leave room for the prompt and the error line
+            0 => (term.size().0 as usize).saturating_sub(2).max(1),
+            size => size,
+        };
+
+        Ok(PagedSelections {
+            prompt: self,
+            defaults,
+            term: term.clone(),
+            page_size,
+            page: 0,
+            done: false,
+        })
+    }
+
+    /// Returns a prompt listing the items of page `page`, checking the ones
+    /// set in `defaults`.
+    ///
+    /// Lazily added items are only loaded up to the end of the page.
+    fn page_prompt(&self, page: usize, page_size: usize, defaults: &[bool]) -> MultiSelect<'a, V> {
+        let start = page * page_size;
+        self.load_items(start + page_size);
+
+        let mut prompt = self.with_items(start, start + page_size);
+        prompt.defaults = slice_of(defaults, start, start + page_size);
+        prompt.explicit_defaults = None;
+        prompt.paged = false;
+
+        // The page count is only shown if it is known without loading all items
+        let pages = self.known_len().map_or(0, |len| page_count(len, page_size));
+        if pages > 1 {
+            let mut indicator = String::new();
+            let _ = self
+                .theme
+                .format_page_indicator(&mut indicator, page, pages);
+            prompt.prompt = Some(match self.prompt {
+                Some(ref text) => format!("{} {}", text, indicator),
+                None => indicator,
+            });
+        }
+
+        prompt
+    }
+}
+
+/// The selections of a [MultiSelect](struct.MultiSelect.html) made page by page.
+///
+/// Returned by [MultiSelect::interact_paginated], every item is the result
+/// of one page. The indices refer to all items of the prompt, not to the
+/// items of the page.
+pub struct PagedSelections<'p, 'a, V = ()> {
+    prompt: &'p MultiSelect<'a, V>,
+    defaults: Vec<bool>,
+    term: Term,
+    page_size: usize,
+    page: usize,
+    done: bool,
+}
+
+impl<'p, 'a, V: Clone> Iterator for PagedSelections<'p, 'a, V> {
+    type Item = io::Result<Vec<usize>>;
+
+    fn next(&mut self) -> Option<io::Result<Vec<usize>>> {
+        let start = self.page * self.page_size;
+
+        if self.done {
+            return None;
+        }
+
+        let prompt = self
+            .prompt
+            .page_prompt(self.page, self.page_size, &self.defaults);
+
+        if prompt.items.borrow().is_empty() {
+            return None;
+        }
+
+        let result = prompt.interact_on(&self.term);
+
+        match result {
+            Ok(checked) => {
+                self.page += 1;
+                Some(Ok(checked.into_iter().map(|idx| start + idx).collect()))
+            }
+            Err(err) => {
+                // Stop after an error instead of asking for the next page
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// The state of an interaction with a [MultiSelect](struct.MultiSelect.html).
+struct State {
+    page: usize,
+    sel: usize,
+    defaults: Vec<bool>,
+    checked: Vec<bool>,
+    search_string: String,
+    show_error: bool,
+    /// The index of the item the preview was generated for and its lines.
+    preview: Option<(usize, Vec<String>)>,
+}
+
+impl State {
+    /// Moves the highlight back to the first item, as the list of matching
+    /// items changes with the search.
+    fn reset_position(&mut self) {
+        self.sel = 0;
+        self.page = 0;
+    }
+}
+
+/// Runs the preview command `cmd` for `item` and returns up to `height`
+/// lines of its output.
+///
+/// If the command cannot be run the error is shown in place of the output.
+fn run_preview(cmd: &str, item: &str, height: usize) -> Vec<String> {
+    let mut words = cmd.split_whitespace();
+    let program = match words.next() {
+        Some(program) => program,
+        None => return vec![],
+    };
+
+    let output = Command::new(program)
+        .args(words)
+        .arg(item)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output();
+
+    match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .take(height)
+            .map(|line| line.replace('\t', "    "))
+            .collect(),
+        Err(err) => vec![err.to_string()],
+    }
+}
+
+/// Returns the values at `start..end`, some of which may be missing at
+/// the end of `values`.
+fn slice_of<T: Clone>(values: &[T], start: usize, end: usize) -> Vec<T> {
+    values[start.min(values.len())..end.min(values.len())].to_vec()
+}
+
+/// Returns the indices of the checked items.
+fn checked_indices(checked: &[bool]) -> Vec<usize> {
+    checked
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, &checked)| if checked { Some(idx) } else { None })
+        .collect()
+}
+
+/// Returns the number of pages needed to show `len` items, at least one.
+fn page_count(len: usize, capacity: usize) -> usize {
+    ((len as f64 / capacity as f64).ceil() as usize).max(1)
+}
+
+/// Keeps the highlighted position within a list of `len` items.
+fn clamp_selection(sel: usize, len: usize) -> usize {
+    sel.min(len.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_count_error() {
+        let mut select = MultiSelect::new();
+        select.min_selections(1).max_selections(2);
+
+        assert!(select
+            .selection_count_error(&[false, false, false])
+            .is_some());
+        assert!(select
+            .selection_count_error(&[true, false, false])
+            .is_none());
+        assert!(select.selection_count_error(&[true, true, false]).is_none());
+        assert!(select.selection_count_error(&[true, true, true]).is_some());
+    }
+
+    #[test]
+    fn test_search_clamps_selection() {
+        let mut select = MultiSelect::new();
+        select.items(&["Apple", "Banana", "Cherry"]);
+
+        // The last item is highlighted when the user types a search that
+        // matches only the first one
+        let keys = vec![
+            Key::ArrowUp,
+            Key::Char('a'),
+            Key::Char('p'),
+            Key::Char('p'),
+            Key::Char(' '),
+            Key::Enter,
+        ];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![0]);
+
+        // Nothing matches, then the search is shortened again
+        let keys = vec![
+            Key::ArrowUp,
+            Key::Char('x'),
+            Key::Char(' '),
+            Key::Backspace,
+            Key::Char(' '),
+            Key::Enter,
+        ];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_defaults_before_items() {
+        let mut select = MultiSelect::new();
+        select.defaults(&[true, false]).items(&["a", "b"]);
+
+        assert_eq!(select.checked_defaults().unwrap(), vec![true, false]);
+    }
+
+    #[test]
+    fn test_defaults_length_mismatch() {
+        let mut select = MultiSelect::new();
+        select.defaults(&[true, false]).items(&["a", "b", "c"]);
+
+        assert_eq!(
+            select.checked_defaults().unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_capacity_huge_page_size() {
+        let mut select = MultiSelect::new();
+        select
+            .items(&["a", "b", "c"])
+            .paged(true)
+            .page_size(usize::MAX);
+
+        assert_eq!(select.capacity(&Term::stderr()), 3);
+    }
+
+    #[test]
+    fn test_items_from_iter_lazy() {
+        let mut select = MultiSelect::new();
+        select
+            .item("a")
+            .items_from_iter((1..).map(|i| i.to_string()));
+
+        select.load_items(3);
+
+        assert_eq!(*select.items.borrow(), vec!["a", "1", "2"]);
+        assert!(select.source.borrow().is_some());
+
+        let mut select = MultiSelect::new();
+        select.items_from_iter(vec!["a", "b"].into_iter());
+
+        select.load_items(usize::MAX);
+
+        assert_eq!(select.items.borrow().len(), 2);
+        assert!(select.source.borrow().is_none());
+    }
+
+    #[test]
+    fn test_interact_with_keys() {
+        let mut select = MultiSelect::new();
+        select.items_checked(&[("a", true), ("b", false), ("c", false)]);
+
+        let keys = vec![
+            Key::Char(' '),
+            Key::ArrowDown,
+            Key::ArrowDown,
+            Key::Char(' '),
+            Key::Enter,
+        ];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![2]);
+
+        // Escape restores the defaults
+        let keys = vec![Key::ArrowDown, Key::Char(' '), Key::Escape];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_checked_map() {
+        let mut select = MultiSelect::new();
+        select.items(&["a", "b", "a", "c"]);
+
+        let map = select.checked_map(&[2, 3]);
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["a"], true);
+        assert_eq!(map["b"], false);
+        assert_eq!(map["c"], true);
+    }
+
+    #[test]
+    fn test_escape_returns_current() {
+        let mut select = MultiSelect::new();
+        select.items_checked(&[("a", true), ("b", false)]);
+
+        let keys = vec![Key::ArrowDown, Key::Char(' '), Key::Escape];
+        assert_eq!(select.interact_with_keys(keys.clone()).unwrap(), vec![0]);
+
+        select.escape_returns_current(true);
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_items_with_values() {
+        let mut select = MultiSelect::valued();
+        select
+            .item("none")
+            .items_with_values(vec![("one", 1), ("two", 2)]);
+
+        assert_eq!(select.checked_values(&[0, 2]), vec![&2]);
+
+        let keys = vec![Key::ArrowDown, Key::Char(' '), Key::Enter];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_select_all_round_trip() {
+        let mut select = MultiSelect::new();
+        select
+            .items(&["apple", "banana", "cherry"])
+            .item_disabled("date");
+
+        let keys = vec![Key::Char('\x01'), Key::Char('\x0e'), Key::Enter];
+        assert_eq!(
+            select.interact_with_keys(keys).unwrap(),
+            Vec::<usize>::new()
+        );
+
+        let keys = vec![Key::Char('\x01'), Key::Enter];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![0, 1, 2]);
+
+        // Pressing the select all key again toggles the items off
+        let keys = vec![Key::Char('\x01'), Key::Char('\x01'), Key::Enter];
+        assert_eq!(
+            select.interact_with_keys(keys).unwrap(),
+            Vec::<usize>::new()
+        );
+
+        // Only the items matching the search are checked
+        let keys = vec![
+            Key::Char('e'),
+            Key::Char('r'),
+            Key::Char('\x01'),
+            Key::Enter,
+        ];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn test_search_keeps_letters() {
+        let mut select = MultiSelect::new();
+        select.items(&["apple", "banana", "cherry"]);
+
+        let keys = vec![Key::Char('a'), Key::Char('n'), Key::Char(' '), Key::Enter];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![1]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_preview() {
+        assert_eq!(run_preview("echo -n", "Cheese", 10), vec!["Cheese"]);
+        assert_eq!(run_preview("printf a\\nb\\nc", "Cheese", 2), vec!["a", "b"]);
+        assert_eq!(
+            run_preview("no-such-preview-command", "Cheese", 10).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_select_all_item() {
+        let mut select = MultiSelect::new();
+        select
+            .with_select_all_item("(Select all)")
+            .items(&["apple", "banana"])
+            .item_disabled("cherry");
+
+        let keys = vec![Key::Char(' '), Key::Enter];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![0, 1]);
+
+        // Unchecking the select all item unchecks everything
+        let keys = vec![Key::Char(' '), Key::Char(' '), Key::Enter];
+        assert_eq!(
+            select.interact_with_keys(keys).unwrap(),
+            Vec::<usize>::new()
+        );
+
+        let keys = vec![Key::ArrowDown, Key::Char(' '), Key::Enter];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_env_answer() {
+        let mut select = MultiSelect::new();
+        select
+            .items(&["a", "b", "c"])
+            .item_disabled("d")
+            .max_selections(2);
+
+        assert_eq!(select.env_answer("CHOICES", "c, 0").unwrap(), vec![0, 2]);
+        assert!(select.env_answer("CHOICES", "d").is_err());
+        assert!(select.env_answer("CHOICES", "a,b,c").is_err());
+        assert!(select.env_answer("CHOICES", "e").is_err());
+    }
+
+    #[test]
+    fn test_clone_loads_items() {
+        let mut select = MultiSelect::new();
+        select.item("a").items_from_iter(vec!["b", "c"].into_iter());
+
+        let clone = select.clone();
+
+        assert_eq!(*clone.items.borrow(), vec!["a", "b", "c"]);
+        assert_eq!(*select.items.borrow(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_horizontal() {
+        let mut select = MultiSelect::new();
+        select.items(&["a", "b", "c"]).horizontal(true).paged(true);
+
+        let keys = vec![
+            Key::ArrowRight,
+            Key::Char(' '),
+            Key::ArrowLeft,
+            Key::ArrowLeft,
+            Key::Char(' '),
+            Key::Enter,
+        ];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_search_resets_position() {
+        let mut select = MultiSelect::new();
+        select.items(&["Apple", "Banana", "Cherry"]);
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, select.theme);
+        let mut state = select.start(&mut render).unwrap();
+
+        state.sel = 2;
+        select
+            .handle_key(&mut state, Key::Char('b'), &mut render, false)
+            .unwrap();
+
+        assert_eq!(state.search_string, "b");
+        assert_eq!(state.sel, 0);
+        assert_eq!(state.page, 0);
+
+        state.sel = 1;
+        select
+            .handle_key(&mut state, Key::Backspace, &mut render, false)
+            .unwrap();
+
+        assert_eq!(state.search_string, "");
+        assert_eq!(state.sel, 0);
+    }
+
+    #[test]
+    fn test_search_kept_when_paging() {
+        let mut select = MultiSelect::new();
+        select
+            .with_prompt("Pick")
+            .items(&["x1", "x2", "x3", "y1"])
+            .paged(true)
+            .page_size(2);
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, select.theme);
+        render.capture_output();
+        let mut state = select.start(&mut render).unwrap();
+
+        for key in vec![Key::Char('x'), Key::ArrowRight, Key::ArrowLeft] {
+            select
+                .handle_key(&mut state, key, &mut render, false)
+                .unwrap();
+            select.render_state(&mut state, &mut render).unwrap();
+        }
+
+        let output = String::from_utf8_lossy(render.captured_output()).into_owned();
+        assert_eq!(output.matches("Pick x").count(), 3);
+        assert_eq!(select.prompt_with_search(""), "Pick");
+    }
+
+    #[test]
+    fn test_redraw_skips_unchanged_lines() {
+        let mut select = MultiSelect::new();
+        select
+            .with_prompt("Pick")
+            .items(&["Apple", "Banana", "Cherry"]);
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, select.theme);
+        render.capture_output();
+        let mut state = select.start(&mut render).unwrap();
+        select.render_state(&mut state, &mut render).unwrap();
+        let first_frame = render.captured_output().len();
+
+        select
+            .handle_key(&mut state, Key::ArrowDown, &mut render, false)
+            .unwrap();
+        render.redraw().unwrap();
+        select.render_state(&mut state, &mut render).unwrap();
+
+        let output = String::from_utf8_lossy(&render.captured_output()[first_frame..]).into_owned();
+        assert!(output.contains("Apple"));
+        assert!(output.contains("Banana"));
+        assert!(!output.contains("Pick"));
+        assert!(!output.contains("Cherry"));
+    }
+
+    #[test]
+    fn test_escape_renders_defaults() {
+        let mut select = MultiSelect::new();
+        select
+            .with_prompt("Pick")
+            .items_checked(&[("a", true), ("b", false)]);
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, select.theme);
+        render.capture_output();
+
+        let keys = vec![Key::ArrowDown, Key::Char(' '), Key::Escape];
+        let chosen = select
+            .run(&mut ScriptedKeys(keys.into_iter()), &mut render, false)
+            .unwrap();
+
+        assert_eq!(chosen, Some(vec![0]));
+
+        let output = String::from_utf8_lossy(render.captured_output()).into_owned();
+        assert!(output.ends_with("Pick: a\n"));
+    }
+
+    #[test]
+    fn test_page_prompt() {
+        let mut select = MultiSelect::new();
+        select
+            .with_prompt("Pick")
+            .items_checked(&[("a", true), ("b", false), ("c", true)]);
+
+        let page = select.page_prompt(1, 2, &[false, false, true]);
+
+        assert_eq!(*page.items.borrow(), vec!["c"]);
+        assert_eq!(page.defaults, vec![true]);
+        assert_eq!(page.prompt.as_deref(), Some("Pick [2/2]"));
+    }
+
+    #[test]
+    fn test_page_prompt_loads_lazily() {
+        let mut select = MultiSelect::new();
+        select
+            .with_prompt("Pick")
+            .items_from_iter((0..).map(|n| format!("Record {}", n)));
+
+        let page = select.page_prompt(1, 2, &[]);
+
+        assert_eq!(*page.items.borrow(), vec!["Record 2", "Record 3"]);
+        assert_eq!(select.items.borrow().len(), 4);
+        // The number of pages is unknown
+        assert_eq!(page.prompt.as_deref(), Some("Pick"));
+    }
+
+    #[test]
+    fn test_search_separator() {
+        struct BarTheme;
+        impl Theme for BarTheme {
+            fn search_separator(&self) -> &str {
+                " | "
+            }
+        }
+
+        let mut select = MultiSelect::with_theme(&BarTheme);
+        select.with_prompt("Pick");
+
+        assert_eq!(select.prompt_with_search("x"), "Pick | x");
+    }
 }
@@ -1,10 +1,20 @@
-use std::io;
+use std::{
+    io,
+    time::{Duration, Instant},
+};
 
-use crate::theme::{SimpleTheme, TermThemeRenderer, Theme};
+use crate::{
+    prompts::{env, keys::read_key_with_io},
+    strength::StrengthMeter,
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
 
-use console::Term;
+use console::{Key, Term};
 use zeroize::Zeroizing;
 
+/// Keys arriving faster than this after the previous one are taken to be pasted.
+const PASTE_THRESHOLD: Duration = Duration::from_millis(5);
+
 /// Renders a password input prompt.
 ///
 /// ## Example usage
@@ -19,11 +29,15 @@ use zeroize::Zeroizing;
 /// println!("Length of the password is: {}", password.len());
 /// # Ok(()) } fn main() { test().unwrap(); }
 /// ```
+#[derive(Clone)]
 pub struct Password<'a> {
     prompt: String,
     theme: &'a dyn Theme,
     allow_empty_password: bool,
     confirmation_prompt: Option<(String, String)>,
+    strength: Option<&'a dyn StrengthMeter>,
+    env_var: Option<String>,
+    allow_paste: bool,
 }
 
 impl<'a> Default for Password<'a> {
@@ -35,7 +49,7 @@ impl<'a> Default for Password<'a> {
 impl<'a> Password<'a> {
     /// Creates a password input prompt.
     pub fn new() -> Password<'static> {
-        Password::with_theme(&SimpleTheme)
+        Password::with_theme(default_theme())
     }
 
     /// Creates a password input prompt with a specific theme.
@@ -45,6 +59,9 @@ impl<'a> Password<'a> {
             theme,
             allow_empty_password: false,
             confirmation_prompt: None,
+            strength: None,
+            env_var: None,
+            allow_paste: true,
         }
     }
 
@@ -55,6 +72,11 @@ impl<'a> Password<'a> {
     }
 
     /// Enables confirmation prompting.
+    ///
+    /// After the first entry the user is asked to repeat the password with
+    /// `prompt`. If the two entries differ `mismatch_err` is shown and both
+    /// passwords are asked for again, the password is only returned once
+    /// both entries match.
     pub fn with_confirmation<A, B>(&mut self, prompt: A, mismatch_err: B) -> &mut Password<'a>
     where
         A: Into<String>,
@@ -64,6 +86,34 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Registers a strength meter.
+    ///
+    /// The strength of the password is rendered below the prompt and updated
+    /// as the user types.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use dialoguer::{Password, StrengthMeter};
+    /// struct Length;
+    ///
+    /// impl StrengthMeter for Length {
+    ///     fn score(&self, password: &str) -> u8 {
+    ///         (password.len() / 4) as u8
+    ///     }
+    /// }
+    ///
+    /// let password = Password::new()
+    ///     .with_prompt("New Password")
+    ///     .strength_with(&Length)
+    ///     .interact()
+    ///     .unwrap();
+    /// ```
+    pub fn strength_with(&mut self, meter: &'a dyn StrengthMeter) -> &mut Password<'a> {
+        self.strength = Some(meter);
+        self
+    }
+
     /// Allows/Disables empty password.
     ///
     /// By default this setting is set to false (i.e. password is not empty).
@@ -72,6 +122,30 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Allows or disallows pasting the password.
+    ///
+    /// Terminals pass pasted text on as if it was typed, so a paste is
+    /// recognized by keys arriving faster than a person can type. When
+    /// disallowed, pasted text is dropped and only typed characters make up
+    /// the password, e.g. to make sure a new password is typed in twice.
+    ///
+    /// The default is to allow pasting, e.g. from a password manager.
+    pub fn allow_paste(&mut self, val: bool) -> &mut Password<'a> {
+        self.allow_paste = val;
+        self
+    }
+
+    /// Reads the password from the environment variable `name` instead of the terminal.
+    ///
+    /// When the variable is set to a non-empty value the prompt is not shown
+    /// and the value is returned as the password, no confirmation is asked.
+    ///
+    /// This allows running the prompt non-interactively, e.g. in CI.
+    pub fn env_var<S: Into<String>>(&mut self, name: S) -> &mut Password<'a> {
+        self.env_var = Some(name.into());
+        self
+    }
+
     /// Enables user interaction and returns the result.
     ///
     /// If the user confirms the result is `true`, `false` otherwise.
@@ -80,47 +154,241 @@ impl<'a> Password<'a> {
         self.interact_on(&Term::stderr())
     }
 
+    /// Enables user interaction and returns the result.
+    ///
+    /// This method is similar to [interact_on_opt](#method.interact_on_opt) except for the fact that it does not allow selection of the terminal.
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(password)` if the user entered one or `None` if the user cancelled with 'Esc'.
+    pub fn interact_opt(&self) -> io::Result<Option<String>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
     /// Like `interact` but allows a specific terminal to be set.
     pub fn interact_on(&self, term: &Term) -> io::Result<String> {
-        let mut render = TermThemeRenderer::new(term, self.theme);
+        self.interact_on_with_io(term, term)
+    }
+
+    /// Like [interact_on](#method.interact_on) but reads the password from `input` and renders on `output`.
+    pub fn interact_on_with_io(&self, input: &Term, output: &Term) -> io::Result<String> {
+        self._interact_on(input, output, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    ///
+    /// To notice 'Esc' the password is read key by key. If `term` is not a
+    /// terminal the password is read as a line and cannot be cancelled.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Password;
+    /// use dialoguer::console::Term;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let password = Password::new()
+    ///     .with_prompt("Password")
+    ///     .interact_on_opt(&Term::stderr())?;
+    ///
+    /// match password {
+    ///     Some(_) => println!("Logging in"),
+    ///     None => println!("Login cancelled"),
+    /// }
+    /// #   Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<String>> {
+        self._interact_on(term, term, true)
+    }
+
+    /// Like [interact_on_opt](#method.interact_on_opt) but reads the password from `input` and renders on `output`.
+    #[inline]
+    pub fn interact_on_opt_with_io(
+        &self,
+        input: &Term,
+        output: &Term,
+    ) -> io::Result<Option<String>> {
+        self._interact_on(input, output, true)
+    }
+
+    fn _interact_on(
+        &self,
+        input: &Term,
+        output: &Term,
+        allow_quit: bool,
+    ) -> io::Result<Option<String>> {
+        if let Some(password) = env::answer(self.env_var.as_deref()) {
+            return Ok(Some(password));
+        }
+
+        let mut render = TermThemeRenderer::new(output, self.theme);
         render.set_prompts_reset_height(false);
 
         loop {
-            let password = Zeroizing::new(self.prompt_password(&mut render, &self.prompt)?);
+            let password =
+                match self.prompt_password(input, &mut render, &self.prompt, allow_quit)? {
+                    Some(password) => Zeroizing::new(password),
+                    None => return quit(&mut render),
+                };
 
             if let Some((ref prompt, ref err)) = self.confirmation_prompt {
-                let pw2 = Zeroizing::new(self.prompt_password(&mut render, &prompt)?);
+                let pw2 = match self.prompt_password(input, &mut render, prompt, allow_quit)? {
+                    Some(password) => Zeroizing::new(password),
+                    None => return quit(&mut render),
+                };
 
                 if *password == *pw2 {
                     render.clear()?;
                     render.password_prompt_selection(&self.prompt)?;
-                    term.flush()?;
-                    return Ok((*password).clone());
+                    output.flush()?;
+                    return Ok(Some((*password).clone()));
                 }
 
                 render.error(err)?;
             } else {
                 render.clear()?;
                 render.password_prompt_selection(&self.prompt)?;
-                term.flush()?;
+                output.flush()?;
 
-                return Ok((*password).clone());
+                return Ok(Some((*password).clone()));
             }
         }
     }
 
-    fn prompt_password(&self, render: &mut TermThemeRenderer, prompt: &str) -> io::Result<String> {
+    /// Reads a password, returns `None` if the user cancelled.
+    fn prompt_password(
+        &self,
+        term: &Term,
+        render: &mut TermThemeRenderer,
+        prompt: &str,
+        allow_quit: bool,
+    ) -> io::Result<Option<String>> {
         loop {
             render.password_prompt(prompt)?;
             render.term().flush()?;
 
-            let input = render.term().read_secure_line()?;
+            let input = match self.strength {
+                Some(meter) if term.features().is_attended() => read_keys(
+                    term,
+                    render,
+                    prompt,
+                    Some(meter),
+                    allow_quit,
+                    self.allow_paste,
+                )?,
+                // Pastes can only be told apart when reading key by key
+                None if (allow_quit || !self.allow_paste) && term.features().is_attended() => {
+                    read_keys(term, render, prompt, None, allow_quit, self.allow_paste)?
+                }
+                _ => Some(term.read_secure_line()?),
+            };
 
             render.add_line();
 
-            if !input.is_empty() || self.allow_empty_password {
-                return Ok(input);
+            match input {
+                Some(input) if input.is_empty() && !self.allow_empty_password => {}
+                input => return Ok(input),
             }
         }
     }
 }
+
+/// Clears the prompt of a cancelled password prompt.
+fn quit(render: &mut TermThemeRenderer) -> io::Result<Option<String>> {
+    render.clear()?;
+    render.show_cursor()?;
+    render.flush()?;
+
+    Ok(None)
+}
+
+/// Reads a password key by key, returns `None` if the user cancelled with 'Esc'.
+///
+/// With a `meter` the strength of the password is rendered below the prompt.
+/// Unless `allow_paste` is set, pasted text is dropped.
+fn read_keys(
+    term: &Term,
+    render: &mut TermThemeRenderer,
+    prompt: &str,
+    meter: Option<&dyn StrengthMeter>,
+    allow_quit: bool,
+    allow_paste: bool,
+) -> io::Result<Option<String>> {
+    let mut password = Zeroizing::new(String::new());
+    let mut last_key = None;
+    // The length of the password before the last character was added
+    let mut typed_len = 0;
+
+    let confirmed = loop {
+        let output = render.term();
+
+        if let Some(meter) = meter {
+            output.write_str("\n")?;
+            output.clear_line()?;
+            render.password_strength(meter.score(&password))?;
+            output.move_cursor_up(1)?;
+            render.password_prompt(prompt)?;
+            output.flush()?;
+        }
+
+        let key = read_key_with_io(term, output)?;
+        let now = Instant::now();
+        let pasted = is_pasted(last_key, now);
+        last_key = Some(now);
+
+        match key {
+            Key::Char(_) | Key::Enter if pasted && !allow_paste => {
+                // The first character of the paste looked typed, drop it as well
+                password.truncate(typed_len);
+            }
+            Key::Char(chr) if !chr.is_ascii_control() => {
+                typed_len = password.len();
+                password.push(chr);
+            }
+            Key::Backspace => {
+                password.pop();
+            }
+            Key::Enter => break true,
+            Key::Escape if allow_quit => break false,
+            Key::Unknown => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "Not a terminal",
+                ))
+            }
+            _ => (),
+        }
+    };
+
+    // Leave the cursor on the line below the prompt, like a newline would
+    let output = render.term();
+    output.write_str("\n")?;
+    output.clear_line()?;
+
+    Ok(if confirmed {
+        Some((*password).clone())
+    } else {
+        None
+    })
+}
+
+/// Returns whether a key pressed at `now` is part of a paste, given when
+/// the previous key was pressed.
+fn is_pasted(last_key: Option<Instant>, now: Instant) -> bool {
+    matches!(last_key, Some(last) if now.duration_since(last) < PASTE_THRESHOLD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_pasted() {
+        let start = Instant::now();
+
+        assert!(!is_pasted(None, start));
+        assert!(is_pasted(Some(start), start + Duration::from_millis(1)));
+        assert!(!is_pasted(Some(start), start + Duration::from_millis(100)));
+    }
+}
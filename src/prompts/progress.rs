@@ -0,0 +1,120 @@
+use std::io;
+
+use crate::theme::{default_theme, Theme};
+
+use console::Term;
+
+/// Renders a progress bar for an operation with a known amount of work.
+///
+/// The bar is redrawn in place on every update. Its characters and colors
+/// are controlled by the theme.
+///
+/// ## Example usage
+///
+/// ```rust,no_run
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// use dialoguer::ProgressBar;
+///
+/// let files = vec!["a.txt", "b.txt", "c.txt"];
+/// let mut bar = ProgressBar::new(files.len() as u64);
+/// bar.message("Copying");
+///
+/// for _file in &files {
+///     // ... copy the file ...
+///     bar.inc(1)?;
+/// }
+///
+/// bar.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ProgressBar<'a> {
+    total: u64,
+    pos: u64,
+    width: usize,
+    message: String,
+    term: Term,
+    theme: &'a dyn Theme,
+}
+
+impl<'a> ProgressBar<'a> {
+    /// Creates a progress bar for `total` units of work.
+    ///
+    /// The bar is rendered on stderr.
+    pub fn new(total: u64) -> ProgressBar<'static> {
+        ProgressBar::with_theme(default_theme(), total)
+    }
+
+    /// Creates a progress bar with a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme, total: u64) -> ProgressBar<'a> {
+        ProgressBar {
+            total,
+            pos: 0,
+            width: 40,
+            message: "".into(),
+            term: Term::stderr(),
+            theme,
+        }
+    }
+
+    /// Sets the message shown next to the bar.
+    pub fn message<S: Into<String>>(&mut self, message: S) -> &mut ProgressBar<'a> {
+        self.message = message.into();
+        self
+    }
+
+    /// Sets the width of the bar in characters.
+    ///
+    /// The default is 40.
+    pub fn width(&mut self, width: usize) -> &mut ProgressBar<'a> {
+        self.width = width;
+        self
+    }
+
+    /// Sets the terminal the bar is rendered on.
+    pub fn term(&mut self, term: &Term) -> &mut ProgressBar<'a> {
+        self.term = term.clone();
+        self
+    }
+
+    /// Returns the current position.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Advances the bar by `delta` and redraws it.
+    pub fn inc(&mut self, delta: u64) -> io::Result<()> {
+        self.set(self.pos.saturating_add(delta))
+    }
+
+    /// Moves the bar to `pos` and redraws it.
+    ///
+    /// Positions past the total are capped to the total.
+    pub fn set(&mut self, pos: u64) -> io::Result<()> {
+        self.pos = pos.min(self.total);
+        self.draw()
+    }
+
+    /// Completes the bar and moves the cursor to the next line.
+    ///
+    /// The completed bar stays on screen.
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.pos = self.total;
+        self.draw()?;
+        self.term.write_line("")?;
+        self.term.show_cursor()?;
+        self.term.flush()
+    }
+
+    fn draw(&self) -> io::Result<()> {
+        let mut frame = String::new();
+        self.theme
+            .format_progress_bar(&mut frame, self.pos, self.total, self.width, &self.message)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+        self.term.hide_cursor()?;
+        self.term.clear_line()?;
+        self.term.write_str(&frame)?;
+        self.term.flush()
+    }
+}
@@ -0,0 +1,170 @@
+use std::{io, ops::Rem};
+
+use crate::{prompts::keys::KeySource, theme::TermThemeRenderer};
+
+use console::Key;
+
+/// The interaction loop shared by the select prompts with a search field.
+///
+/// The prompts differ in which items match the search and in what order,
+/// which the `filter` passed to [run](#method.run) decides.
+pub struct SearchList<'a> {
+    pub items: &'a [String],
+    pub prompt: &'a str,
+    pub default: usize,
+    pub clear: bool,
+}
+
+impl<'a> SearchList<'a> {
+    /// Lets the user search and select an item.
+    ///
+    /// `filter` returns the indices of the items matching a search in the
+    /// order they are listed, along with the positions of the matching
+    /// characters to highlight. Returns the index of the selected item, or
+    /// `None` if the user cancelled with 'Esc'.
+    pub fn run<F>(
+        &self,
+        input: &mut dyn KeySource,
+        render: &mut TermThemeRenderer,
+        allow_quit: bool,
+        filter: F,
+    ) -> io::Result<Option<usize>>
+    where
+        F: Fn(&str) -> Vec<(usize, Vec<usize>)>,
+    {
+        let mut search_term = String::new();
+        let mut sel = self.default.min(self.items.len().saturating_sub(1));
+
+        loop {
+            // Leave room for the search field
+            let capacity = (render.term().size().0 as usize).saturating_sub(1).max(1);
+            let filtered = filter(&search_term);
+
+            sel = sel.min(filtered.len().saturating_sub(1));
+
+            render.fuzzy_select_prompt(self.prompt, &search_term)?;
+
+            for (idx, &(item_idx, ref matches)) in filtered
+                .iter()
+                .enumerate()
+                .skip(sel / capacity * capacity)
+                .take(capacity)
+            {
+                render.fuzzy_select_prompt_item(&self.items[item_idx], idx == sel, matches)?;
+            }
+
+            render.hide_cursor()?;
+            render.flush()?;
+
+            match input.read_key()? {
+                Key::ArrowDown | Key::ArrowUp | Key::Home | Key::End | Key::Enter
+                    if filtered.is_empty() => {}
+                Key::ArrowDown => {
+                    sel = (sel as u64 + 1).rem(filtered.len() as u64) as usize;
+                }
+                Key::ArrowUp => {
+                    sel = (sel + filtered.len() - 1) % filtered.len();
+                }
+                Key::Home => {
+                    sel = 0;
+                }
+                Key::End => {
+                    sel = filtered.len() - 1;
+                }
+                Key::Escape if allow_quit => {
+                    if self.clear {
+                        render.clear()?;
+                    }
+
+                    render.show_cursor()?;
+                    render.flush()?;
+
+                    return Ok(None);
+                }
+                Key::Enter => {
+                    let item_idx = filtered[sel].0;
+
+                    if self.clear {
+                        render.clear()?;
+                    }
+
+                    render.select_prompt_selection(self.prompt, &self.items[item_idx])?;
+                    render.show_cursor()?;
+                    render.flush()?;
+
+                    return Ok(Some(item_idx));
+                }
+                Key::Backspace => {
+                    search_term.pop();
+                }
+                Key::Char(chr) if !chr.is_ascii_control() => {
+                    search_term.push(chr);
+                    sel = 0;
+                }
+                _ => {}
+            }
+
+            render.redraw()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{prompts::keys::ScriptedKeys, theme::SimpleTheme};
+
+    use console::Term;
+
+    fn run_keys(list: &SearchList, keys: Vec<Key>) -> io::Result<Option<usize>> {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, &SimpleTheme);
+        render.capture_output();
+
+        // Matches items starting with the search
+        let filter = |search: &str| {
+            list.items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.starts_with(search))
+                .map(|(idx, _)| (idx, (0..search.chars().count()).collect()))
+                .collect()
+        };
+
+        list.run(
+            &mut ScriptedKeys(keys.into_iter()),
+            &mut render,
+            true,
+            filter,
+        )
+    }
+
+    #[test]
+    fn test_search_and_select() {
+        let items = vec!["apple".to_string(), "avocado".into(), "banana".into()];
+        let list = SearchList {
+            items: &items,
+            prompt: "Fruit",
+            default: 2,
+            clear: true,
+        };
+
+        assert_eq!(run_keys(&list, vec![Key::Enter]).unwrap(), Some(2));
+
+        let keys = vec![Key::Char('a'), Key::ArrowDown, Key::Enter];
+        assert_eq!(run_keys(&list, keys).unwrap(), Some(1));
+
+        // Enter is ignored while nothing matches
+        let keys = vec![
+            Key::Char('x'),
+            Key::Enter,
+            Key::Backspace,
+            Key::End,
+            Key::Enter,
+        ];
+        assert_eq!(run_keys(&list, keys).unwrap(), Some(2));
+
+        assert_eq!(run_keys(&list, vec![Key::Escape]).unwrap(), None);
+    }
+}
@@ -1,6 +1,20 @@
-use std::{io, ops::Rem};
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    io,
+    ops::{Range, Rem},
+};
 
-use crate::theme::{SimpleTheme, TermThemeRenderer, Theme};
+use crate::{
+    prompts::{
+        env,
+        keys::{KeySource, ScriptedKeys},
+        mouse::MouseCapture,
+        search::SearchList,
+        select_with_search::substring_match,
+    },
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
 
 use console::{Key, Term};
 
@@ -16,7 +30,7 @@ use console::{Key, Term};
 ///     Select,
 ///     theme::ColorfulTheme
 /// };
-/// use console::Term;
+/// use dialoguer::console::Term;
 ///
 /// fn main() -> std::io::Result<()> {
 ///     let items = vec!["Item 1", "item 2"];
@@ -33,13 +47,24 @@ use console::{Key, Term};
 ///     Ok(())
 /// }
 /// ```
+#[derive(Clone)]
 pub struct Select<'a> {
     default: usize,
-    items: Vec<String>,
+    items: Vec<Item<'a>>,
+    groups: Vec<(usize, String)>,
     prompt: Option<String>,
     clear: bool,
     theme: &'a dyn Theme,
     paged: bool,
+    cursor_char: Option<char>,
+    mouse_scroll: bool,
+    vim_mode: bool,
+    wrap: bool,
+    horizontal: bool,
+    inline: bool,
+    searchable: bool,
+    delimiter: String,
+    env_var: Option<String>,
 }
 
 impl<'a> Default for Select<'a> {
@@ -50,8 +75,8 @@ impl<'a> Default for Select<'a> {
 
 impl<'a> Select<'a> {
     /// Creates a select prompt builder with default theme.
-    pub fn new() -> Select<'static> {
-        Select::with_theme(&SimpleTheme)
+    pub fn new() -> Select<'a> {
+        Select::with_theme(default_theme())
     }
 
     /// Creates a select prompt builder with a specific theme.
@@ -76,10 +101,20 @@ impl<'a> Select<'a> {
         Select {
             default: !0,
             items: vec![],
+            groups: vec![],
             prompt: None,
             clear: true,
             theme,
             paged: false,
+            cursor_char: None,
+            mouse_scroll: false,
+            vim_mode: false,
+            wrap: true,
+            horizontal: false,
+            inline: false,
+            searchable: false,
+            delimiter: "  ".into(),
+            env_var: None,
         }
     }
 
@@ -91,6 +126,99 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Indicates whether the selection wraps around at the ends of the list.
+    ///
+    /// Without wrapping the selection stops at the first and last item, and
+    /// in paged mode the number of items on other pages is shown above and
+    /// below the current page.
+    ///
+    /// Wrapping is enabled by default.
+    pub fn wrap(&mut self, val: bool) -> &mut Select<'a> {
+        self.wrap = val;
+        self
+    }
+
+    /// Renders the items side by side on one line.
+    ///
+    /// Saves vertical space for short lists. The left and right arrows move
+    /// the selection and the items wrap to more lines if they do not fit the
+    /// width of the terminal. Paging and group headers do not apply in
+    /// horizontal mode.
+    ///
+    /// The default is to render one item per line.
+    pub fn horizontal(&mut self, val: bool) -> &mut Select<'a> {
+        self.horizontal = val;
+        self
+    }
+
+    /// Renders the items on the line of the prompt, e.g. `Continue? yes / [no]`.
+    ///
+    /// Meant for choices between two or three short items. The left and right
+    /// arrows move the selection, which the theme highlights. Paging and
+    /// group headers do not apply in inline mode.
+    ///
+    /// The default is to render one item per line.
+    pub fn inline(&mut self, val: bool) -> &mut Select<'a> {
+        self.inline = val;
+        self
+    }
+
+    /// Enables or disables filtering the items by typing.
+    ///
+    /// The prompt behaves like [SelectWithSearch](struct.SelectWithSearch.html):
+    /// typed characters are added to a search shown after the prompt and
+    /// only the items containing it (case-insensitive) are listed, the first
+    /// of them highlighted. Backspace removes the last character of the
+    /// search. As all characters go to the search, the vim-style keys and
+    /// selecting with space or quitting with 'q' are not available, and
+    /// paging, group headers and [horizontal](#method.horizontal) layout do
+    /// not apply. Searching does not apply in [inline](#method.inline) mode.
+    ///
+    /// The default is to not filter the items.
+    pub fn searchable(&mut self, val: bool) -> &mut Select<'a> {
+        self.searchable = val;
+        self
+    }
+
+    /// Sets the text between items in [horizontal](#method.horizontal) mode.
+    ///
+    /// The default is two spaces.
+    pub fn horizontal_delimiter<S: Into<String>>(&mut self, val: S) -> &mut Select<'a> {
+        self.delimiter = val.into();
+        self
+    }
+
+    /// Enables or disables vim-style navigation.
+    ///
+    /// The `j`, `k`, `h` and `l` keys always move the selection. In vim mode
+    /// `g` additionally jumps to the first and `G` to the last item.
+    ///
+    /// Vim mode is disabled by default.
+    pub fn vim_mode(&mut self, val: bool) -> &mut Select<'a> {
+        self.vim_mode = val;
+        self
+    }
+
+    /// Enables or disables moving the selection with the mouse wheel.
+    ///
+    /// While the prompt is shown the terminal reports mouse events to it, which
+    /// usually prevents selecting text with the mouse. Terminals that do not
+    /// support mouse reporting keep working with the keyboard only.
+    ///
+    /// Mouse scrolling is disabled by default.
+    pub fn mouse_scroll(&mut self, val: bool) -> &mut Select<'a> {
+        self.mouse_scroll = val;
+        self
+    }
+
+    /// Overrides the cursor glyph shown next to the active item.
+    ///
+    /// By default the glyph is chosen by the theme.
+    pub fn cursor_char(&mut self, val: char) -> &mut Select<'a> {
+        self.cursor_char = Some(val);
+        self
+    }
+
     /// Indicates whether select menu should be ereased from the screen after interaction.
     ///
     /// The default is to clear the menu.
@@ -102,6 +230,8 @@ impl<'a> Select<'a> {
     /// Sets initial selected element when select menu is rendered
     ///
     /// Element is indicated by the index at which it appears in `item` method invocation or `items` slice.
+    /// If there is no element at the index, interacting with the prompt fails
+    /// with an error of kind `InvalidInput`.
     pub fn default(&mut self, val: usize) -> &mut Select<'a> {
         self.default = val;
         self
@@ -123,12 +253,14 @@ impl<'a> Select<'a> {
     /// }
     /// ```
     pub fn item<T: ToString>(&mut self, item: T) -> &mut Select<'a> {
-        self.items.push(item.to_string());
+        self.items.push(Item::Text(item.to_string()));
         self
     }
 
     /// Adds multiple items to the selector.
     ///
+    /// Accepts slices as well as any other iterable of items.
+    ///
     /// ## Examples
     /// ```rust,no_run
     /// use dialoguer::Select;
@@ -144,13 +276,109 @@ impl<'a> Select<'a> {
     ///     Ok(())
     /// }
     /// ```
-    pub fn items<T: ToString>(&mut self, items: &[T]) -> &mut Select<'a> {
+    pub fn items<T, I>(&mut self, items: I) -> &mut Select<'a>
+    where
+        T: ToString,
+        I: IntoIterator<Item = T>,
+    {
+        for item in items {
+            self.items.push(Item::Text(item.to_string()));
+        }
+        self
+    }
+
+    /// Adds multiple items to the selector by reference.
+    ///
+    /// Unlike [items](#method.items) the items are not converted to strings
+    /// upfront, only the items on screen are formatted when the prompt is
+    /// rendered. This avoids the conversion cost for long lists.
+    ///
+    /// ## Examples
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let ports: Vec<u16> = (1024..65535).collect();
+    ///     let selection = Select::new()
+    ///         .paged(true)
+    ///         .items_ref(&ports)
+    ///         .interact()?;
+    ///
+    ///     println!("Listening on port {}", ports[selection]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn items_ref<T: Display>(&mut self, items: &'a [T]) -> &mut Select<'a> {
         for item in items {
-            self.items.push(item.to_string());
+            self.items.push(Item::Ref(item));
+        }
+        self
+    }
+
+    /// Adds items of different types to the selector by reference.
+    ///
+    /// Like [items_ref](#method.items_ref) the items are only formatted when
+    /// they are rendered.
+    ///
+    /// ## Examples
+    /// ```rust,no_run
+    /// use std::{fmt::Display, net::Ipv4Addr};
+    ///
+    /// use dialoguer::Select;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let localhost = Ipv4Addr::LOCALHOST;
+    ///     let port = 8080;
+    ///     let items: [&dyn Display; 2] = [&localhost, &port];
+    ///
+    ///     let selection = Select::new().items_dyn(&items).interact()?;
+    ///
+    ///     println!("Using {}", items[selection]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn items_dyn(&mut self, items: &[&'a dyn Display]) -> &mut Select<'a> {
+        for &item in items {
+            self.items.push(Item::Ref(item));
         }
         self
     }
 
+    /// Adds multiple items to the selector under a labelled header.
+    ///
+    /// The header is rendered above the items but cannot be selected.
+    /// Indices returned by the prompt still refer to the position of the
+    /// item among all added items, headers are not counted.
+    ///
+    /// ## Examples
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let recent = vec!["main.rs"];
+    ///     let all = vec!["lib.rs", "main.rs", "theme.rs"];
+    ///     let selection: usize = Select::new()
+    ///         .group("Recently used", &recent)
+    ///         .group("All files", &all)
+    ///         .interact()?;
+    ///
+    ///     println!("{}", recent.iter().chain(all.iter()).nth(selection).unwrap());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn group<L, T, I>(&mut self, label: L, items: I) -> &mut Select<'a>
+    where
+        L: Into<String>,
+        T: ToString,
+        I: IntoIterator<Item = T>,
+    {
+        self.groups.push((self.items.len(), label.into()));
+        self.items(items)
+    }
+
     /// Sets the select prompt.
     ///
     /// When a prompt is set the system also prints out a confirmation after
@@ -175,6 +403,18 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Reads the answer from the environment variable `name` instead of the terminal.
+    ///
+    /// When the variable is set to a non-empty value the prompt is not shown.
+    /// The value is either the text of an item or its index. An answer that
+    /// is not valid results in an error of kind `InvalidInput`.
+    ///
+    /// This allows running the prompt non-interactively, e.g. in CI.
+    pub fn env_var<S: Into<String>>(&mut self, name: S) -> &mut Select<'a> {
+        self.env_var = Some(name.into());
+        self
+    }
+
     /// Enables user interaction and returns the result.
     ///
     /// Similar to [interact_on](#method.interact_on) except for the fact that it does not allow selection of the terminal.
@@ -184,13 +424,76 @@ impl<'a> Select<'a> {
         self.interact_on(&Term::stderr())
     }
 
+    /// Alias of [interact](#method.interact) that spells out that an index is returned.
+    #[inline]
+    pub fn interact_index(&self) -> io::Result<usize> {
+        self.interact()
+    }
+
+    /// Enables user interaction and returns the index of the selected item.
+    ///
+    /// This method is similar to [interact_on_opt](#method.interact_on_opt) except for the fact that it does not allow selection of the terminal.
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(index)` if user selected one of items or `None` if user cancelled with 'Esc' or 'q'.
+    pub fn interact_index_opt(&self) -> io::Result<Option<usize>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
     /// Enables user interaction and returns the result.
     ///
     /// This method is similar to [interact_on_opt](#method.interact_on_opt) except for the fact that it does not allow selection of the terminal.
     /// The dialog is rendered on stderr.
     /// Result contains `Some(index)` if user selected one of items or `None` if user cancelled with 'Esc' or 'q'.
+    ///
+    /// Deprecated in favor of [interact_index_opt](#method.interact_index_opt).
+    #[deprecated(note = "Use interact_index_opt() instead", since = "0.8.0")]
+    #[inline]
     pub fn interact_opt(&self) -> io::Result<Option<usize>> {
-        self.interact_on_opt(&Term::stderr())
+        self.interact_index_opt()
+    }
+
+    /// Enables user interaction and returns the selected item itself.
+    ///
+    /// Like [interact](#method.interact) but maps the selected index back to the item text.
+    pub fn interact_text(&self) -> io::Result<String> {
+        self.interact_text_on(&Term::stderr())
+    }
+
+    /// Enables user interaction and returns the selected item itself.
+    ///
+    /// Like [interact_index_opt](#method.interact_index_opt) but maps the selected index back to the item text.
+    ///
+    /// ## Examples
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let flavor = Select::new()
+    ///         .items(&["Vanilla", "Chocolate"])
+    ///         .interact_text_opt()?;
+    ///
+    ///     match flavor {
+    ///         Some(flavor) => println!("Enjoy your {}!", flavor),
+    ///         None => println!("Maybe next time"),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn interact_text_opt(&self) -> io::Result<Option<String>> {
+        self.interact_text_on_opt(&Term::stderr())
+    }
+
+    /// Like [interact_text](#method.interact_text) but allows a specific terminal to be set.
+    pub fn interact_text_on(&self, term: &Term) -> io::Result<String> {
+        Ok(self.items[self.interact_on(term)?].text().into_owned())
+    }
+
+    /// Like [interact_text_opt](#method.interact_text_opt) but allows a specific terminal to be set.
+    pub fn interact_text_on_opt(&self, term: &Term) -> io::Result<Option<String>> {
+        Ok(self
+            .interact_on_opt(term)?
+            .map(|idx| self.items[idx].text().into_owned()))
     }
 
     /// Like [interact](#method.interact) but allows a specific terminal to be set.
@@ -198,7 +501,7 @@ impl<'a> Select<'a> {
     /// ## Examples
     ///```rust,no_run
     /// use dialoguer::Select;
-    /// use console::Term;
+    /// use dialoguer::console::Term;
     ///
     /// fn main() -> std::io::Result<()> {
     ///     let selection = Select::new()
@@ -212,16 +515,47 @@ impl<'a> Select<'a> {
     /// }
     ///```
     pub fn interact_on(&self, term: &Term) -> io::Result<usize> {
-        self._interact_on(term, false)?
+        self.interact_on_with_io(term, term)
+    }
+
+    /// Like [interact_on](#method.interact_on) but with the item selected
+    /// initially given at interaction time.
+    ///
+    /// This is the same as calling [default](#method.default) before
+    /// [interact_on](#method.interact_on), the prompt itself is not changed.
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{console::Term, Select};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut select = Select::new();
+    ///     select.items(&["Option A", "Option B"]);
+    ///
+    ///     let last_choice = 1;
+    ///
+    ///     let selection = select.interact_on_with_default(&Term::stderr(), last_choice)?;
+    ///
+    ///     println!("User selected option at index {}", selection);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn interact_on_with_default(&self, term: &Term, default: usize) -> io::Result<usize> {
+        self.clone().default(default).interact_on(term)
+    }
+
+    /// Like [interact_on](#method.interact_on) but reads keys from `input` and renders on `output`.
+    pub fn interact_on_with_io(&self, input: &Term, output: &Term) -> io::Result<usize> {
+        self._interact_on(input, output, false)?
             .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
     }
 
-    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    /// Like [interact_index_opt](#method.interact_index_opt) but allows a specific terminal to be set.
     ///
     /// ## Examples
     /// ```rust,no_run
     /// use dialoguer::Select;
-    /// use console::Term;
+    /// use dialoguer::console::Term;
     ///
     /// fn main() -> std::io::Result<()> {
     ///     let selection = Select::new()
@@ -239,12 +573,113 @@ impl<'a> Select<'a> {
     /// ```
     #[inline]
     pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<usize>> {
-        self._interact_on(term, true)
+        self._interact_on(term, term, true)
+    }
+
+    /// Like [interact_on_opt](#method.interact_on_opt) but reads keys from `input` and renders on `output`.
+    #[inline]
+    pub fn interact_on_opt_with_io(
+        &self,
+        input: &Term,
+        output: &Term,
+    ) -> io::Result<Option<usize>> {
+        self._interact_on(input, output, true)
     }
 
-    /// Like `interact` but allows a specific terminal to be set.
-    fn _interact_on(&self, term: &Term, allow_quit: bool) -> io::Result<Option<usize>> {
+    /// Runs the prompt on the given key presses instead of reading them from a terminal.
+    ///
+    /// The prompt is rendered into a buffer rather than on the terminal, which
+    /// allows testing the interaction without a TTY. If the keys run out before
+    /// an item is selected an error of kind `UnexpectedEof` is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use dialoguer::{console::Key, Select};
+    ///
+    /// let selection = Select::new()
+    ///     .items(&["Option A", "Option B"])
+    ///     .default(0)
+    ///     .interact_with_keys(vec![Key::ArrowDown, Key::Enter])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(selection, 1);
+    /// ```
+    pub fn interact_with_keys<I>(&self, keys: I) -> io::Result<usize>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, self.theme);
+        render.capture_output();
+
+        self.run(&mut ScriptedKeys(keys.into_iter()), &mut render, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like `interact` but allows specific terminals to be set.
+    fn _interact_on(
+        &self,
+        mut input: &Term,
+        term: &Term,
+        allow_quit: bool,
+    ) -> io::Result<Option<usize>> {
+        if let Some(ref var) = self.env_var {
+            if let Some(value) = env::answer(Some(var)) {
+                return env::find_item(var, &value, self.items.iter().map(Item::text)).map(Some);
+            }
+        }
+
+        let _mouse = MouseCapture::new(term, self.mouse_scroll)?;
+        let mut render = TermThemeRenderer::new(term, self.theme);
+
+        self.run(&mut input, &mut render, allow_quit)
+    }
+
+    /// Returns the label of the group starting at the item at `idx`.
+    ///
+    /// Groups without items are left out, their headers would stack up
+    /// above the next group.
+    fn group_label(&self, idx: usize) -> Option<&str> {
+        self.groups
+            .iter()
+            .rev()
+            .find(|&&(start, _)| start == idx)
+            .map(|(_, label)| label.as_str())
+    }
+
+    /// Splits the items into pages that fit into `rows` terminal rows,
+    /// counting the group headers rendered above their first items.
+    fn page_ranges(&self, rows: usize) -> Vec<Range<usize>> {
+        let mut pages = vec![];
+        let mut start = 0;
+        let mut used = 0;
+
+        for idx in 0..self.items.len() {
+            let height = 1 + self.group_label(idx).is_some() as usize;
+
+            if used + height > rows && idx > start {
+                pages.push(start..idx);
+                start = idx;
+                used = 0;
+            }
+
+            used += height;
+        }
+
+        pages.push(start..self.items.len());
+        pages
+    }
+
+    /// The interaction loop shared by the terminal and the scripted interaction.
+    fn run(
+        &self,
+        input: &mut dyn KeySource,
+        render: &mut TermThemeRenderer,
+        allow_quit: bool,
+    ) -> io::Result<Option<usize>> {
         let mut page = 0;
+        let term = render.term();
 
         if self.items.is_empty() {
             return Err(io::Error::new(
@@ -253,61 +688,135 @@ impl<'a> Select<'a> {
             ));
         }
 
-        let capacity = if self.paged {
-            term.size().0 as usize - 1
-        } else {
-            self.items.len()
-        };
+        if self.default != !0 && self.default >= self.items.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Default {} given to `Select` with {} items",
+                    self.default,
+                    self.items.len()
+                ),
+            ));
+        }
 
-        let pages = (self.items.len() as f64 / capacity as f64).ceil() as usize;
+        if self.searchable && !self.inline {
+            let texts: Vec<_> = self
+                .items
+                .iter()
+                .map(|item| item.text().into_owned())
+                .collect();
+            let list = SearchList {
+                items: &texts,
+                prompt: self.prompt.as_deref().unwrap_or(""),
+                default: if self.default == !0 { 0 } else { self.default },
+                clear: self.clear,
+            };
 
-        let mut render = TermThemeRenderer::new(term, self.theme);
+            return list.run(input, render, allow_quit, |search| {
+                texts
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, text)| substring_match(text, search).map(|pos| (idx, pos)))
+                    .collect()
+            });
+        }
+
+        render.set_cursor_char(self.cursor_char);
         let mut sel = self.default;
 
-        if let Some(ref prompt) = self.prompt {
+        // Inline items are rendered on the prompt line
+        if let Some(prompt) = self.prompt.as_ref().filter(|_| !self.inline) {
             render.select_prompt(prompt)?;
         }
 
-        let mut size_vec = Vec::new();
+        loop {
+            // Recomputed on every render so resizing the terminal takes effect
+            let pages = if self.paged && !self.horizontal && !self.inline {
+                // Without wrapping two more lines are needed for the indicators
+                let reserved = if self.wrap { 1 } else { 3 } + self.prompt.is_some() as usize;
+                self.page_ranges((term.size().0 as usize).saturating_sub(reserved).max(1))
+            } else {
+                self.page_ranges(usize::MAX)
+            };
+
+            if sel != !0 {
+                page = pages.iter().position(|range| sel < range.end).unwrap_or(0);
+            } else {
+                page = page.min(pages.len() - 1);
+            }
 
-        for items in self
-            .items
-            .iter()
-            .flat_map(|i| i.split('\n'))
-            .collect::<Vec<_>>()
-        {
-            let size = &items.len();
-            size_vec.push(*size);
-        }
+            let hidden_above = pages[page].start;
+            let hidden_below = self.items.len() - pages[page].end;
 
-        loop {
-            for (idx, item) in self
-                .items
-                .iter()
-                .enumerate()
-                .skip(page * capacity)
-                .take(capacity)
-            {
-                render.select_prompt_item(item, sel == idx)?;
+            if !self.wrap && hidden_above > 0 {
+                render.select_prompt_more_items(true, hidden_above)?;
             }
 
-            term.hide_cursor()?;
-            term.flush()?;
+            if self.inline {
+                let texts: Vec<_> = self.items.iter().map(Item::text).collect();
+                let items: Vec<_> = texts
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, text)| (text.as_ref(), sel == idx))
+                    .collect();
+
+                render.select_prompt_inline(self.prompt.as_deref().unwrap_or(""), &items)?;
+            } else if self.horizontal {
+                let texts: Vec<_> = self.items.iter().map(Item::text).collect();
+                let items: Vec<_> = texts
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, text)| (text.as_ref(), sel == idx))
+                    .collect();
+
+                render.select_prompt_items_horizontal(&items, &self.delimiter)?;
+            } else {
+                for (idx, item) in self
+                    .items
+                    .iter()
+                    .enumerate()
+                    .skip(pages[page].start)
+                    .take(pages[page].len())
+                {
+                    if let Some(label) = self.group_label(idx) {
+                        render.select_prompt_group_header(label)?;
+                    }
+
+                    render.select_prompt_item(&item.text(), sel == idx)?;
+                }
+            }
+
+            if !self.wrap && hidden_below > 0 {
+                render.select_prompt_more_items(false, hidden_below)?;
+            }
+
+            render.hide_cursor()?;
+            render.flush()?;
+
+            let key = match input.read_key()? {
+                Key::Char('g') if self.vim_mode => Key::Home,
+                Key::Char('G') if self.vim_mode => Key::End,
+                Key::ArrowLeft | Key::Char('h') if self.horizontal || self.inline => Key::ArrowUp,
+                Key::ArrowRight | Key::Char('l') if self.horizontal || self.inline => {
+                    Key::ArrowDown
+                }
+                key => key,
+            };
 
-            match term.read_key()? {
+            match key {
                 Key::ArrowDown | Key::Char('j') => {
                     if sel == !0 {
                         sel = 0;
-                    } else {
+                    } else if self.wrap || sel < self.items.len() - 1 {
                         sel = (sel as u64 + 1).rem(self.items.len() as u64) as usize;
                     }
                 }
                 Key::Escape | Key::Char('q') => {
                     if allow_quit {
                         if self.clear {
-                            term.clear_last_lines(self.items.len())?;
-                            term.show_cursor()?;
-                            term.flush()?;
+                            render.clear_preserve_prompt()?;
+                            render.show_cursor()?;
+                            render.flush()?;
                         }
 
                         return Ok(None);
@@ -316,31 +825,37 @@ impl<'a> Select<'a> {
                 Key::ArrowUp | Key::Char('k') => {
                     if sel == !0 {
                         sel = self.items.len() - 1;
-                    } else {
+                    } else if self.wrap || sel > 0 {
                         sel = ((sel as i64 - 1 + self.items.len() as i64)
                             % (self.items.len() as i64)) as usize;
                     }
                 }
+                Key::Home => {
+                    sel = 0;
+                }
+                Key::End => {
+                    sel = self.items.len() - 1;
+                }
                 Key::ArrowLeft | Key::Char('h') => {
-                    if self.paged {
+                    if self.paged && (self.wrap || page > 0) {
                         if page == 0 {
-                            page = pages - 1;
+                            page = pages.len() - 1;
                         } else {
                             page -= 1;
                         }
 
-                        sel = page * capacity;
+                        sel = pages[page].start;
                     }
                 }
                 Key::ArrowRight | Key::Char('l') => {
-                    if self.paged {
-                        if page == pages - 1 {
+                    if self.paged && (self.wrap || page < pages.len() - 1) {
+                        if page == pages.len() - 1 {
                             page = 0;
                         } else {
                             page += 1;
                         }
 
-                        sel = page * capacity;
+                        sel = pages[page].start;
                     }
                 }
 
@@ -350,22 +865,35 @@ impl<'a> Select<'a> {
                     }
 
                     if let Some(ref prompt) = self.prompt {
-                        render.select_prompt_selection(prompt, &self.items[sel])?;
+                        render.select_prompt_selection(prompt, &self.items[sel].text())?;
                     }
 
-                    term.show_cursor()?;
-                    term.flush()?;
+                    render.show_cursor()?;
+                    render.flush()?;
 
                     return Ok(Some(sel));
                 }
                 _ => {}
             }
 
-            if sel != !0 && (sel < page * capacity || sel >= (page + 1) * capacity) {
-                page = sel / capacity;
-            }
+            render.redraw_preserve_prompt()?;
+        }
+    }
+}
+
+/// An item of a [Select](struct.Select.html).
+#[derive(Clone)]
+enum Item<'a> {
+    Text(String),
+    /// Formatted only when it is rendered.
+    Ref(&'a dyn Display),
+}
 
-            render.clear_preserve_prompt(&size_vec)?;
+impl<'a> Item<'a> {
+    fn text(&self) -> Cow<'_, str> {
+        match *self {
+            Item::Text(ref text) => Cow::Borrowed(text),
+            Item::Ref(item) => Cow::Owned(item.to_string()),
         }
     }
 }
@@ -374,6 +902,14 @@ impl<'a> Select<'a> {
 mod tests {
     use super::*;
 
+    fn texts(select: &Select) -> Vec<String> {
+        select
+            .items
+            .iter()
+            .map(|item| item.text().into_owned())
+            .collect()
+    }
+
     #[test]
     fn test_str() {
         let selections = &[
@@ -384,7 +920,7 @@ mod tests {
         ];
 
         assert_eq!(
-            Select::new().default(0).items(&selections[..]).items,
+            texts(Select::new().default(0).items(&selections[..])),
             selections
         );
     }
@@ -394,7 +930,7 @@ mod tests {
         let selections = vec!["a".to_string(), "b".to_string()];
 
         assert_eq!(
-            Select::new().default(0).items(&selections[..]).items,
+            texts(Select::new().default(0).items(&selections[..])),
             selections
         );
     }
@@ -407,8 +943,238 @@ mod tests {
         let selections = &[a, b];
 
         assert_eq!(
-            Select::new().default(0).items(&selections[..]).items,
+            texts(Select::new().default(0).items(&selections[..])),
             selections
         );
     }
+
+    #[test]
+    fn test_iter() {
+        let selections = vec!["a".to_string(), "b".to_string()];
+
+        assert_eq!(
+            texts(
+                Select::new()
+                    .default(0)
+                    .items(selections.iter().map(|s| s.to_uppercase()))
+            ),
+            vec!["A", "B"]
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let mut select = Select::new();
+        select
+            .item("a")
+            .group("First", &["b", "c"])
+            .group("Second", &["d"]);
+
+        assert_eq!(texts(&select), vec!["a", "b", "c", "d"]);
+        assert_eq!(
+            select.groups,
+            vec![(1, "First".to_string()), (3, "Second".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_page_ranges() {
+        let mut select = Select::new();
+        select
+            .item("a")
+            .group("Empty", Vec::<String>::new())
+            .group("First", &["b", "c"])
+            .group("Second", &["d", "e"]);
+
+        assert_eq!(select.group_label(1), Some("First"));
+        assert_eq!(select.group_label(2), None);
+
+        // Every page fits, counting the header above its first item
+        assert_eq!(select.page_ranges(3), vec![0..2, 2..4, 4..5]);
+        assert_eq!(select.page_ranges(10), vec![0..5]);
+    }
+
+    #[test]
+    fn test_interact_with_keys() {
+        let mut select = Select::new();
+        select.items(&["a", "b", "c"]).default(0);
+
+        assert_eq!(
+            select
+                .interact_with_keys(vec![Key::ArrowDown, Key::ArrowDown, Key::Enter])
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            select
+                .interact_with_keys(vec![Key::ArrowDown])
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn test_no_wrap() {
+        let mut select = Select::new();
+        select.items(&["a", "b", "c"]).default(0).wrap(false);
+
+        assert_eq!(
+            select
+                .interact_with_keys(vec![Key::ArrowUp, Key::Enter])
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            select
+                .interact_with_keys(vec![Key::ArrowDown; 5].into_iter().chain(Some(Key::Enter)))
+                .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_default_out_of_bounds() {
+        let mut select = Select::new();
+        select.items(&["a", "b"]).default(2);
+
+        assert_eq!(
+            select
+                .interact_with_keys(vec![Key::Enter])
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_items_ref() {
+        let ports = [80, 443];
+        let mut select = Select::new();
+        select.item("22").items_ref(&ports).default(0);
+
+        assert_eq!(texts(&select), vec!["22", "80", "443"]);
+        assert_eq!(
+            select
+                .interact_with_keys(vec![Key::ArrowDown, Key::ArrowDown, Key::Enter])
+                .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_items_dyn() {
+        let port = 8080;
+        let host = "localhost";
+        let mut select = Select::new();
+        select.items_dyn(&[&host, &port]);
+
+        assert_eq!(texts(&select), vec!["localhost", "8080"]);
+    }
+
+    #[test]
+    fn test_env_var() {
+        std::env::set_var("DIALOGUER_TEST_SELECT", "b");
+        std::env::set_var("DIALOGUER_TEST_SELECT_INDEX", "2");
+
+        let mut select = Select::new();
+        select.items(&["a", "b", "c"]);
+
+        let term = Term::stderr();
+
+        select.env_var("DIALOGUER_TEST_SELECT");
+        assert_eq!(select.interact_on(&term).unwrap(), 1);
+
+        select.env_var("DIALOGUER_TEST_SELECT_INDEX");
+        assert_eq!(select.interact_text_on(&term).unwrap(), "c");
+    }
+
+    #[test]
+    fn test_searchable() {
+        let mut select = Select::new();
+        select
+            .items(&["Apple", "Banana", "Blueberry", "Cherry"])
+            .searchable(true);
+
+        let keys = vec![
+            Key::Char('b'),
+            Key::Char('x'),
+            Key::Backspace,
+            Key::ArrowDown,
+            Key::Enter,
+        ];
+        assert_eq!(select.interact_with_keys(keys).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_searchable_redraw_keeps_prompt() {
+        let mut select = Select::new();
+        select
+            .with_prompt("Fruit")
+            .items(&["Apple", "Banana", "Cherry"])
+            .searchable(true);
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, select.theme);
+        render.capture_output();
+
+        let keys = vec![Key::ArrowDown, Key::Escape];
+        let sel = select
+            .run(&mut ScriptedKeys(keys.into_iter()), &mut render, true)
+            .unwrap();
+
+        assert_eq!(sel, None);
+
+        let output = String::from_utf8_lossy(render.captured_output()).into_owned();
+        assert_eq!(output.matches("Fruit").count(), 1);
+        assert_eq!(output.matches("Apple").count(), 2);
+        assert_eq!(output.matches("Banana").count(), 2);
+        assert_eq!(output.matches("Cherry").count(), 1);
+    }
+
+    #[test]
+    fn test_inline() {
+        let mut select = Select::new();
+        select
+            .with_prompt("Continue?")
+            .items(&["yes", "no", "maybe"])
+            .default(0)
+            .inline(true);
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, select.theme);
+        render.capture_output();
+
+        let keys = vec![Key::ArrowRight, Key::Enter];
+        let sel = select
+            .run(&mut ScriptedKeys(keys.into_iter()), &mut render, false)
+            .unwrap();
+
+        assert_eq!(sel, Some(1));
+
+        let output = String::from_utf8_lossy(render.captured_output()).into_owned();
+        assert!(output.contains("Continue?: yes / [no] / maybe"));
+    }
+
+    #[test]
+    fn test_horizontal() {
+        let mut select = Select::new();
+        select
+            .items(&["a", "b", "c"])
+            .default(0)
+            .horizontal(true)
+            .horizontal_delimiter(" | ");
+
+        assert_eq!(
+            select
+                .interact_with_keys(vec![
+                    Key::ArrowRight,
+                    Key::ArrowRight,
+                    Key::ArrowLeft,
+                    Key::Enter
+                ])
+                .unwrap(),
+            1
+        );
+    }
 }
@@ -0,0 +1,219 @@
+use std::io;
+
+use crate::{
+    prompts::search::SearchList,
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
+
+use console::Term;
+
+/// Renders a select prompt with a search bar.
+///
+/// Behaves like [Select](struct.Select.html), but typing narrows down the
+/// items to those containing the search (case-insensitive). Unlike
+/// [FuzzySelect](struct.FuzzySelect.html) the matching items keep their
+/// order. Interaction returns the index of the selected item in the order
+/// the items were added.
+///
+/// ## Example usage
+///
+/// ```rust,no_run
+/// use dialoguer::SelectWithSearch;
+///
+/// fn main() -> std::io::Result<()> {
+///     let items = vec!["Berlin", "London", "Paris", "Prague"];
+///     let selection = SelectWithSearch::new()
+///         .with_prompt("Pick a city")
+///         .items(&items)
+///         .interact()?;
+///
+///     println!("Flying to {}", items[selection]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SelectWithSearch<'a> {
+    default: usize,
+    items: Vec<String>,
+    prompt: String,
+    clear: bool,
+    theme: &'a dyn Theme,
+}
+
+impl<'a> Default for SelectWithSearch<'a> {
+    fn default() -> SelectWithSearch<'a> {
+        SelectWithSearch::new()
+    }
+}
+
+impl<'a> SelectWithSearch<'a> {
+    /// Creates a select prompt with a search bar.
+    pub fn new() -> SelectWithSearch<'static> {
+        SelectWithSearch::with_theme(default_theme())
+    }
+
+    /// Creates a select prompt with a search bar and a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme) -> SelectWithSearch<'a> {
+        SelectWithSearch {
+            default: 0,
+            items: vec![],
+            prompt: "".into(),
+            clear: true,
+            theme,
+        }
+    }
+
+    /// Indicates whether the prompt should be erased from the screen after interaction.
+    ///
+    /// The default is to clear the prompt.
+    pub fn clear(&mut self, val: bool) -> &mut SelectWithSearch<'a> {
+        self.clear = val;
+        self
+    }
+
+    /// Sets the item highlighted before the user starts searching.
+    pub fn default(&mut self, val: usize) -> &mut SelectWithSearch<'a> {
+        self.default = val;
+        self
+    }
+
+    /// Add a single item to the selector.
+    pub fn item<T: ToString>(&mut self, item: T) -> &mut SelectWithSearch<'a> {
+        self.items.push(item.to_string());
+        self
+    }
+
+    /// Adds multiple items to the selector.
+    pub fn items<T, I>(&mut self, items: I) -> &mut SelectWithSearch<'a>
+    where
+        T: ToString,
+        I: IntoIterator<Item = T>,
+    {
+        for item in items {
+            self.items.push(item.to_string());
+        }
+        self
+    }
+
+    /// Prefaces the search bar with a prompt.
+    pub fn with_prompt<S: Into<String>>(&mut self, prompt: S) -> &mut SelectWithSearch<'a> {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains index of the selected item.
+    pub fn interact(&self) -> io::Result<usize> {
+        self.interact_on(&Term::stderr())
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(index)` if the user selected an item or `None` if the user cancelled with 'Esc'.
+    pub fn interact_opt(&self) -> io::Result<Option<usize>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
+    /// Like [interact](#method.interact) but allows a specific terminal to be set.
+    pub fn interact_on(&self, term: &Term) -> io::Result<usize> {
+        self._interact_on(term, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<usize>> {
+        self._interact_on(term, true)
+    }
+
+    fn _interact_on(&self, mut term: &Term, allow_quit: bool) -> io::Result<Option<usize>> {
+        if self.items.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Empty list of items given to `SelectWithSearch`",
+            ));
+        }
+
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let list = SearchList {
+            items: &self.items,
+            prompt: &self.prompt,
+            default: self.default,
+            clear: self.clear,
+        };
+
+        list.run(&mut term, &mut render, allow_quit, |search_term| {
+            self.filter_items(search_term)
+        })
+    }
+
+    /// Returns the indices of the items containing `search_term`, along with
+    /// the positions of the matching characters.
+    fn filter_items(&self, search_term: &str) -> Vec<(usize, Vec<usize>)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| substring_match(item, search_term).map(|pos| (idx, pos)))
+            .collect()
+    }
+}
+
+/// Returns the positions of the characters of the first occurrence of
+/// `pattern` in `item`, ignoring case.
+pub(crate) fn substring_match(item: &str, pattern: &str) -> Option<Vec<usize>> {
+    // Lowercasing can turn one character into several, so every lowercased
+    // character remembers the position of the one it came from.
+    let item: Vec<(usize, char)> = item
+        .chars()
+        .enumerate()
+        .flat_map(|(idx, chr)| chr.to_lowercase().map(move |lower| (idx, lower)))
+        .collect();
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+
+    if pattern.is_empty() {
+        return Some(vec![]);
+    }
+
+    item.windows(pattern.len())
+        .find(|window| {
+            window
+                .iter()
+                .map(|&(_, chr)| chr)
+                .eq(pattern.iter().cloned())
+        })
+        .map(|window| {
+            let mut positions: Vec<_> = window.iter().map(|&(idx, _)| idx).collect();
+            positions.dedup();
+            positions
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substring_match() {
+        assert_eq!(substring_match("Prague", "AG"), Some(vec![2, 3]));
+        assert_eq!(substring_match("Prague", ""), Some(vec![]));
+        assert_eq!(substring_match("Prague", "pg"), None);
+    }
+
+    #[test]
+    fn test_filter_items_keeps_order() {
+        let mut select = SelectWithSearch::new();
+        select.items(&["Berlin", "London", "Paris", "Prague"]);
+
+        let filtered: Vec<_> = select
+            .filter_items("r")
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect();
+
+        assert_eq!(filtered, vec![0, 2, 3]);
+    }
+}
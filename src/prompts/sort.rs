@@ -1,6 +1,9 @@
 use std::{io, ops::Rem};
 
-use crate::theme::{SimpleTheme, TermThemeRenderer, Theme};
+use crate::{
+    prompts::keys::read_key_with_io,
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
 
 use console::{Key, Term};
 
@@ -21,12 +24,14 @@ use console::{Key, Term};
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct Sort<'a> {
     items: Vec<String>,
     prompt: Option<String>,
     clear: bool,
     theme: &'a dyn Theme,
     paged: bool,
+    page_size: Option<usize>,
 }
 
 impl<'a> Default for Sort<'a> {
@@ -38,7 +43,7 @@ impl<'a> Default for Sort<'a> {
 impl<'a> Sort<'a> {
     /// Creates a sort prompt.
     pub fn new() -> Sort<'static> {
-        Sort::with_theme(&SimpleTheme)
+        Sort::with_theme(default_theme())
     }
 
     /// Creates a sort prompt with a specific theme.
@@ -49,6 +54,7 @@ impl<'a> Sort<'a> {
             prompt: None,
             theme,
             paged: false,
+            page_size: None,
         }
     }
 
@@ -58,6 +64,15 @@ impl<'a> Sort<'a> {
         self
     }
 
+    /// Declares the page size for the element
+    ///
+    /// A page size of `0` fits the page to the height of the terminal,
+    /// which is the default.
+    pub fn page_size(&mut self, val: usize) -> &mut Sort<'a> {
+        self.page_size = if val == 0 { None } else { Some(val) };
+        self
+    }
+
     /// Sets the clear behavior of the menu.
     ///
     /// The default is to clear the menu after user interaction.
@@ -73,7 +88,13 @@ impl<'a> Sort<'a> {
     }
 
     /// Adds multiple items to the selector.
-    pub fn items<T: ToString>(&mut self, items: &[T]) -> &mut Sort<'a> {
+    ///
+    /// Accepts slices as well as any other iterable of items.
+    pub fn items<T, I>(&mut self, items: I) -> &mut Sort<'a>
+    where
+        T: ToString,
+        I: IntoIterator<Item = T>,
+    {
         for item in items {
             self.items.push(item.to_string());
         }
@@ -97,10 +118,47 @@ impl<'a> Sort<'a> {
         self.interact_on(&Term::stderr())
     }
 
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(order)` if the user hit 'Enter' or `None` if the user cancelled with 'Esc'.
+    pub fn interact_opt(&self) -> io::Result<Option<Vec<usize>>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
     /// Like [interact](#method.interact) but allows a specific terminal to be set.
     pub fn interact_on(&self, term: &Term) -> io::Result<Vec<usize>> {
-        let mut page = 0;
+        self.interact_on_with_io(term, term)
+    }
+
+    /// Like [interact_on](#method.interact_on) but reads keys from `input` and renders on `output`.
+    pub fn interact_on_with_io(&self, input: &Term, output: &Term) -> io::Result<Vec<usize>> {
+        self._interact_on(input, output, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<Vec<usize>>> {
+        self._interact_on(term, term, true)
+    }
+
+    /// Like [interact_on_opt](#method.interact_on_opt) but reads keys from `input` and renders on `output`.
+    #[inline]
+    pub fn interact_on_opt_with_io(
+        &self,
+        input: &Term,
+        output: &Term,
+    ) -> io::Result<Option<Vec<usize>>> {
+        self._interact_on(input, output, true)
+    }
 
+    fn _interact_on(
+        &self,
+        input: &Term,
+        output: &Term,
+        allow_quit: bool,
+    ) -> io::Result<Option<Vec<usize>>> {
         if self.items.is_empty() {
             return Err(io::Error::new(
                 io::ErrorKind::Other,
@@ -108,32 +166,30 @@ impl<'a> Sort<'a> {
             ));
         }
 
-        let capacity = if self.paged {
-            term.size().0 as usize - 1
-        } else {
-            self.items.len()
-        };
-
-        let pages = (self.items.len() as f64 / capacity as f64).ceil() as usize;
-
-        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut render = TermThemeRenderer::new(output, self.theme);
         let mut sel = 0;
 
         if let Some(ref prompt) = self.prompt {
             render.sort_prompt(prompt)?;
         }
 
-        let mut size_vec = Vec::new();
-
-        for items in self.items.iter().as_slice() {
-            let size = &items.len();
-            size_vec.push(*size);
-        }
-
         let mut order: Vec<_> = (0..self.items.len()).collect();
         let mut checked: bool = false;
 
         loop {
+            // Recomputed on every render so resizing the terminal takes effect
+            let capacity = if self.paged {
+                match self.page_size {
+                    Some(page_size) => page_size,
+                    None => (output.size().0 as usize).saturating_sub(1).max(1),
+                }
+            } else {
+                self.items.len()
+            };
+
+            let pages = (self.items.len() as f64 / capacity as f64).ceil() as usize;
+            let mut page = sel / capacity;
+
             for (idx, item) in order
                 .iter()
                 .enumerate()
@@ -143,10 +199,10 @@ impl<'a> Sort<'a> {
                 render.sort_prompt_item(&self.items[*item], checked, sel == idx)?;
             }
 
-            term.hide_cursor()?;
-            term.flush()?;
+            output.hide_cursor()?;
+            render.flush()?;
 
-            match term.read_key()? {
+            match read_key_with_io(input, output)? {
                 Key::ArrowDown | Key::Char('j') => {
                     let old_sel = sel;
 
@@ -233,7 +289,16 @@ impl<'a> Sort<'a> {
                 Key::Char(' ') => {
                     checked = !checked;
                 }
-                // TODO: Key::Escape
+                Key::Escape if allow_quit => {
+                    if self.clear {
+                        render.clear()?;
+                    }
+
+                    output.show_cursor()?;
+                    output.flush()?;
+
+                    return Ok(None);
+                }
                 Key::Enter => {
                     if self.clear {
                         render.clear()?;
@@ -248,19 +313,15 @@ impl<'a> Sort<'a> {
                         render.sort_prompt_selection(prompt, &list[..])?;
                     }
 
-                    term.show_cursor()?;
-                    term.flush()?;
+                    output.show_cursor()?;
+                    output.flush()?;
 
-                    return Ok(order);
+                    return Ok(Some(order));
                 }
                 _ => {}
             }
 
-            if sel < page * capacity || sel >= (page + 1) * capacity {
-                page = sel / capacity;
-            }
-
-            render.clear_preserve_prompt(&size_vec)?;
+            render.redraw_preserve_prompt()?;
         }
     }
 }
@@ -0,0 +1,174 @@
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::theme::{default_theme, Theme};
+
+use console::Term;
+
+/// Renders a spinner while a long-running operation is in progress.
+///
+/// The spinner is drawn by a background thread until the returned
+/// [SpinnerHandle](struct.SpinnerHandle.html) is stopped or dropped.
+///
+/// ## Example usage
+///
+/// ```rust,no_run
+/// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+/// use dialoguer::Spinner;
+///
+/// let spinner = Spinner::new().message("Downloading").start()?;
+/// // ... do the actual work ...
+/// spinner.stop()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Spinner<'a> {
+    message: String,
+    tick_strings: Vec<String>,
+    interval: Duration,
+    theme: &'a dyn Theme,
+}
+
+impl<'a> Default for Spinner<'a> {
+    fn default() -> Spinner<'a> {
+        Spinner::new()
+    }
+}
+
+impl<'a> Spinner<'a> {
+    /// Creates a spinner.
+    pub fn new() -> Spinner<'static> {
+        Spinner::with_theme(default_theme())
+    }
+
+    /// Creates a spinner with a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme) -> Spinner<'a> {
+        Spinner {
+            message: "".into(),
+            tick_strings: vec!["-".into(), "\\".into(), "|".into(), "/".into()],
+            interval: Duration::from_millis(100),
+            theme,
+        }
+    }
+
+    /// Sets the message shown next to the spinner.
+    pub fn message<S: Into<String>>(&mut self, message: S) -> &mut Spinner<'a> {
+        self.message = message.into();
+        self
+    }
+
+    /// Sets the frames of the spinner animation.
+    ///
+    /// Empty lists are ignored.
+    pub fn tick_strings(&mut self, ticks: &[&str]) -> &mut Spinner<'a> {
+        if !ticks.is_empty() {
+            self.tick_strings = ticks.iter().map(|tick| tick.to_string()).collect();
+        }
+        self
+    }
+
+    /// Sets the time between two frames.
+    ///
+    /// The default is 100 milliseconds.
+    pub fn interval(&mut self, interval: Duration) -> &mut Spinner<'a> {
+        self.interval = interval;
+        self
+    }
+
+    /// Starts drawing the spinner and returns a handle to stop it.
+    ///
+    /// The spinner is rendered on stderr.
+    pub fn start(&self) -> io::Result<SpinnerHandle> {
+        self.start_on(&Term::stderr())
+    }
+
+    /// Like [start](#method.start) but allows a specific terminal to be set.
+    pub fn start_on(&self, term: &Term) -> io::Result<SpinnerHandle> {
+        // The theme cannot be shared with the drawing thread, so every
+        // frame is formatted upfront.
+        let mut frames = Vec::with_capacity(self.tick_strings.len());
+
+        for tick in &self.tick_strings {
+            let mut frame = String::new();
+            self.theme
+                .format_spinner(&mut frame, tick, &self.message)
+                .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+            frames.push(frame);
+        }
+
+        term.hide_cursor()?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stop = stop.clone();
+            let term = term.clone();
+            let interval = self.interval;
+
+            thread::spawn(move || -> io::Result<()> {
+                for frame in frames.iter().cycle() {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    term.clear_line()?;
+                    term.write_str(frame)?;
+                    term.flush()?;
+                    thread::sleep(interval);
+                }
+
+                Ok(())
+            })
+        };
+
+        Ok(SpinnerHandle {
+            term: term.clone(),
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// Handle of a running [Spinner](struct.Spinner.html).
+///
+/// Dropping the handle stops the spinner as well.
+pub struct SpinnerHandle {
+    term: Term,
+    stop: Arc<AtomicBool>,
+    thread: Option<thread::JoinHandle<io::Result<()>>>,
+}
+
+impl SpinnerHandle {
+    /// Stops the spinner and clears its line.
+    pub fn stop(mut self) -> io::Result<()> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let thread = match self.thread.take() {
+            Some(thread) => thread,
+            None => return Ok(()),
+        };
+
+        self.stop.store(true, Ordering::SeqCst);
+        thread
+            .join()
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "Spinner thread panicked"))??;
+
+        self.term.clear_line()?;
+        self.term.show_cursor()?;
+        self.term.flush()
+    }
+}
+
+impl Drop for SpinnerHandle {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
@@ -0,0 +1,316 @@
+use std::io;
+
+use crate::{
+    prompts::keys::KeySource,
+    theme::{default_theme, TermThemeRenderer, Theme},
+};
+
+use console::{measure_text_width, Key, Term};
+
+/// Renders a selection prompt for the rows of a table.
+///
+/// Every column is as wide as its longest value, so the rows line up below
+/// the header. Interaction returns the index of the selected row.
+///
+/// ## Example usage
+///
+/// ```rust,no_run
+/// use dialoguer::TableSelect;
+///
+/// fn main() -> std::io::Result<()> {
+///     let selection = TableSelect::new()
+///         .with_prompt("Pick a record")
+///         .columns(&["ID", "Name", "Status"])
+///         .row(&["1", "alpha", "running"])
+///         .row(&["2", "beta", "stopped"])
+///         .interact()?;
+///
+///     println!("Record at row {} selected", selection);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TableSelect<'a> {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+    default: usize,
+    prompt: Option<String>,
+    clear: bool,
+    theme: &'a dyn Theme,
+}
+
+impl<'a> Default for TableSelect<'a> {
+    fn default() -> TableSelect<'a> {
+        TableSelect::new()
+    }
+}
+
+impl<'a> TableSelect<'a> {
+    /// Creates a table select prompt.
+    pub fn new() -> TableSelect<'static> {
+        TableSelect::with_theme(default_theme())
+    }
+
+    /// Creates a table select prompt with a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme) -> TableSelect<'a> {
+        TableSelect {
+            columns: vec![],
+            rows: vec![],
+            default: 0,
+            prompt: None,
+            clear: true,
+            theme,
+        }
+    }
+
+    /// Indicates whether the table should be erased from the screen after interaction.
+    ///
+    /// The default is to clear the table.
+    pub fn clear(&mut self, val: bool) -> &mut TableSelect<'a> {
+        self.clear = val;
+        self
+    }
+
+    /// Sets the row selected initially.
+    pub fn default(&mut self, val: usize) -> &mut TableSelect<'a> {
+        self.default = val;
+        self
+    }
+
+    /// Sets the headers of the columns.
+    pub fn columns<T: ToString>(&mut self, columns: &[T]) -> &mut TableSelect<'a> {
+        self.columns = columns.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Adds a single row to the table.
+    ///
+    /// Rows with fewer cells than there are columns are padded with empty cells.
+    pub fn row<T: ToString>(&mut self, cells: &[T]) -> &mut TableSelect<'a> {
+        self.rows
+            .push(cells.iter().map(ToString::to_string).collect());
+        self
+    }
+
+    /// Adds multiple rows to the table.
+    pub fn rows<T: ToString>(&mut self, rows: &[Vec<T>]) -> &mut TableSelect<'a> {
+        for row in rows {
+            self.row(row);
+        }
+        self
+    }
+
+    /// Prefaces the table with a prompt.
+    ///
+    /// When a prompt is set the system also prints out a confirmation after
+    /// the selection.
+    pub fn with_prompt<S: Into<String>>(&mut self, prompt: S) -> &mut TableSelect<'a> {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains the index of the selected row.
+    pub fn interact(&self) -> io::Result<usize> {
+        self.interact_on(&Term::stderr())
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on stderr.
+    /// Result contains `Some(index)` if the user selected a row or `None` if the user cancelled with 'Esc' or 'q'.
+    pub fn interact_opt(&self) -> io::Result<Option<usize>> {
+        self.interact_on_opt(&Term::stderr())
+    }
+
+    /// Like [interact](#method.interact) but allows a specific terminal to be set.
+    pub fn interact_on(&self, term: &Term) -> io::Result<usize> {
+        self._interact_on(term, false)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))
+    }
+
+    /// Like [interact_opt](#method.interact_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on_opt(&self, term: &Term) -> io::Result<Option<usize>> {
+        self._interact_on(term, true)
+    }
+
+    fn _interact_on(&self, mut input: &Term, allow_quit: bool) -> io::Result<Option<usize>> {
+        let mut render = TermThemeRenderer::new(input, self.theme);
+        self.run(&mut input, &mut render, allow_quit)
+    }
+
+    fn run(
+        &self,
+        input: &mut dyn KeySource,
+        render: &mut TermThemeRenderer,
+        allow_quit: bool,
+    ) -> io::Result<Option<usize>> {
+        if self.rows.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Empty list of rows given to `TableSelect`",
+            ));
+        }
+
+        let term = render.term();
+        let (header, rows) = layout_table(&self.columns, &self.rows);
+        let mut sel = self.default.min(self.rows.len() - 1);
+
+        if let Some(ref prompt) = self.prompt {
+            render.select_prompt(prompt)?;
+        }
+
+        loop {
+            // Leave room for the header
+            let capacity = (term.size().0 as usize).saturating_sub(2).max(1);
+
+            if !self.columns.is_empty() {
+                render.table_header(&header)?;
+            }
+
+            for (idx, row) in rows
+                .iter()
+                .enumerate()
+                .skip(sel / capacity * capacity)
+                .take(capacity)
+            {
+                if idx == sel {
+                    render.table_selected_row(row)?;
+                } else {
+                    render.table_row(row)?;
+                }
+            }
+
+            render.hide_cursor()?;
+            render.flush()?;
+
+            match input.read_key()? {
+                Key::ArrowDown | Key::Char('j') => {
+                    sel = (sel + 1) % rows.len();
+                }
+                Key::ArrowUp | Key::Char('k') => {
+                    sel = (sel + rows.len() - 1) % rows.len();
+                }
+                Key::Home => {
+                    sel = 0;
+                }
+                Key::End => {
+                    sel = rows.len() - 1;
+                }
+                Key::Escape | Key::Char('q') if allow_quit => {
+                    if self.clear {
+                        render.clear_preserve_prompt()?;
+                    }
+
+                    render.show_cursor()?;
+                    render.flush()?;
+
+                    return Ok(None);
+                }
+                Key::Enter | Key::Char(' ') => {
+                    if self.clear {
+                        render.clear()?;
+                    }
+
+                    if let Some(ref prompt) = self.prompt {
+                        render.select_prompt_selection(prompt, &self.rows[sel].join(" "))?;
+                    }
+
+                    render.show_cursor()?;
+                    render.flush()?;
+
+                    return Ok(Some(sel));
+                }
+                _ => {}
+            }
+
+            render.redraw_preserve_prompt()?;
+        }
+    }
+}
+
+/// Pads the cells of `columns` and `rows` to the width of the longest value
+/// in their column and joins them into lines.
+///
+/// Returns the header line and the line of every row.
+fn layout_table(columns: &[String], rows: &[Vec<String>]) -> (String, Vec<String>) {
+    let count = rows
+        .iter()
+        .map(Vec::len)
+        .chain(Some(columns.len()))
+        .max()
+        .unwrap_or(0);
+    let mut widths = vec![0; count];
+
+    for line in Some(columns)
+        .into_iter()
+        .chain(rows.iter().map(|row| &row[..]))
+    {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(measure_text_width(cell));
+        }
+    }
+
+    let join = |line: &[String]| {
+        let mut text = String::new();
+
+        for (idx, width) in widths.iter().enumerate() {
+            let cell = line.get(idx).map_or("", String::as_str);
+
+            if idx > 0 {
+                text.push_str("  ");
+            }
+
+            text.push_str(cell);
+            text.push_str(&" ".repeat(width - measure_text_width(cell)));
+        }
+
+        text.trim_end().to_string()
+    };
+
+    (join(columns), rows.iter().map(|row| join(row)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::prompts::keys::ScriptedKeys;
+
+    #[test]
+    fn test_layout_table() {
+        let columns = vec!["ID".to_string(), "Name".to_string()];
+        let rows = vec![
+            vec!["1".to_string(), "alpha".to_string()],
+            vec!["100".to_string()],
+        ];
+
+        let (header, rows) = layout_table(&columns, &rows);
+
+        assert_eq!(header, "ID   Name");
+        assert_eq!(rows, vec!["1    alpha", "100"]);
+    }
+
+    #[test]
+    fn test_select_row() {
+        let mut table = TableSelect::new();
+        table
+            .columns(&["ID", "Name"])
+            .rows(&[vec!["1", "alpha"], vec!["2", "beta"]]);
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, table.theme);
+        render.capture_output();
+
+        let keys = vec![Key::ArrowDown, Key::Enter];
+        let sel = table
+            .run(&mut ScriptedKeys(keys.into_iter()), &mut render, false)
+            .unwrap();
+
+        assert_eq!(sel, Some(1));
+    }
+}
@@ -0,0 +1,10 @@
+//! Provides strength meters for password inputs
+
+/// Trait for rating the strength of a password.
+pub trait StrengthMeter {
+    /// Invoked with the password entered so far after every keystroke.
+    ///
+    /// Returns a score from 0 (weakest) to 4 (strongest), higher values are
+    /// treated as 4.
+    fn score(&self, password: &str) -> u8;
+}
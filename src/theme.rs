@@ -1,10 +1,26 @@
 //! Customizes the rendering of the elements.
-use std::{fmt, io};
+use std::{
+    cell::{Cell, RefCell},
+    fmt, io, mem,
+    sync::RwLock,
+};
 
-use console::{style, Style, StyledObject, Term};
+use console::{
+    measure_text_width, strip_ansi_codes, style, truncate_str, Style, StyledObject, Term,
+};
 
 /// Implements a theme for dialoguer.
 pub trait Theme {
+    /// Returns the style the renderer applies to the prompt text of select,
+    /// multi select, input and confirm prompts.
+    ///
+    /// The default leaves the text unstyled. `ColorfulTheme` styles prompts
+    /// with its `prompt_style` field while formatting them instead.
+    #[inline]
+    fn prompt_style(&self) -> Style {
+        Style::new()
+    }
+
     /// Formats a prompt.
     #[inline]
     fn format_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
@@ -17,6 +33,32 @@ pub trait Theme {
         write!(f, "error: {}", err)
     }
 
+    /// Returns the prefix of messages printed with [render_success].
+    #[inline]
+    fn success_prefix(&self) -> String {
+        "success:".into()
+    }
+
+    /// Returns the prefix of messages printed with [render_error].
+    #[inline]
+    fn error_prefix(&self) -> String {
+        "error:".into()
+    }
+
+    /// Formats the message of a failed input validation.
+    #[inline]
+    fn format_validation_error(&self, f: &mut dyn fmt::Write, err: &str) -> fmt::Result {
+        self.format_error(f, err)
+    }
+
+    /// Returns the style of the option a confirm prompt selects on Enter.
+    ///
+    /// The default leaves the option unstyled, it is shown in uppercase.
+    #[inline]
+    fn confirm_prompt_default_selection(&self) -> Style {
+        Style::new()
+    }
+
     /// Formats a confirm prompt.
     fn format_confirm_prompt(
         &self,
@@ -27,10 +69,11 @@ pub trait Theme {
         if !prompt.is_empty() {
             write!(f, "{} ", &prompt)?;
         }
+        let highlight = self.confirm_prompt_default_selection();
         match default {
             None => write!(f, "[y/n] ")?,
-            Some(true) => write!(f, "[Y/n] ")?,
-            Some(false) => write!(f, "[y/N] ")?,
+            Some(true) => write!(f, "[{}/n] ", highlight.apply_to("Y"))?,
+            Some(false) => write!(f, "[y/{}] ", highlight.apply_to("N"))?,
         }
         Ok(())
     }
@@ -49,6 +92,67 @@ pub trait Theme {
         }
     }
 
+    /// Formats a confirm prompt answered with custom texts.
+    ///
+    /// The user answers by typing the first character of `yes` or `no`. With
+    /// the default texts `yes` and `no` this renders like
+    /// [format_confirm_prompt](#method.format_confirm_prompt).
+    fn format_confirm_prompt_with_labels(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        default: Option<bool>,
+        yes: &str,
+        no: &str,
+    ) -> fmt::Result {
+        if yes == "yes" && no == "no" {
+            return self.format_confirm_prompt(f, prompt, default);
+        }
+
+        if !prompt.is_empty() {
+            write!(f, "{} ", &prompt)?;
+        }
+
+        let (yes_key, no_key) = confirm_keys(yes, no);
+        let highlight = self.confirm_prompt_default_selection();
+
+        match default {
+            None => write!(f, "[{}/{}] ", yes_key, no_key),
+            Some(true) => write!(
+                f,
+                "[{}/{}] ",
+                highlight.apply_to(yes_key.to_uppercase()),
+                no_key
+            ),
+            Some(false) => write!(
+                f,
+                "[{}/{}] ",
+                yes_key,
+                highlight.apply_to(no_key.to_uppercase())
+            ),
+        }
+    }
+
+    /// Formats a confirm prompt answered with custom texts after selection.
+    fn format_confirm_prompt_selection_with_labels(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        selection: bool,
+        yes: &str,
+        no: &str,
+    ) -> fmt::Result {
+        if yes == "yes" && no == "no" {
+            return self.format_confirm_prompt_selection(f, prompt, selection);
+        }
+
+        if prompt.is_empty() {
+            write!(f, "{}", if selection { yes } else { no })
+        } else {
+            write!(f, "{} {}", &prompt, if selection { yes } else { no })
+        }
+    }
+
     /// Formats an input prompt.
     fn format_input_prompt(
         &self,
@@ -74,6 +178,18 @@ pub trait Theme {
         write!(f, "{}: {}", prompt, sel)
     }
 
+    /// Formats the text added to an input by a completion.
+    #[inline]
+    fn format_input_completion(&self, f: &mut dyn fmt::Write, completion: &str) -> fmt::Result {
+        write!(f, "{}", completion)
+    }
+
+    /// Formats the default of an input shown as ghost text while the input is empty.
+    #[inline]
+    fn format_input_placeholder(&self, f: &mut dyn fmt::Write, placeholder: &str) -> fmt::Result {
+        write!(f, "{}", style(placeholder).for_stderr().dim())
+    }
+
     /// Formats a password prompt.
     #[inline]
     fn format_password_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
@@ -90,6 +206,14 @@ pub trait Theme {
         self.format_input_prompt_selection(f, prompt, "[hidden]")
     }
 
+    /// Formats the strength of the password entered so far.
+    ///
+    /// `score` ranges from 0 (weakest) to 4 (strongest).
+    fn format_password_strength(&self, f: &mut dyn fmt::Write, score: u8) -> fmt::Result {
+        let score = score.min(4) as usize;
+        write!(f, "[{}{}]", "#".repeat(score), " ".repeat(4 - score))
+    }
+
     /// Formats a select prompt.
     #[inline]
     fn format_select_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
@@ -113,12 +237,33 @@ pub trait Theme {
         self.format_prompt(f, prompt)
     }
 
+    /// Formats the page indicator of a paged prompt.
+    ///
+    /// `page` is zero-based, `pages` is the total number of pages. The
+    /// default writes `[2/4]` for the second of four pages, themes can
+    /// override it to write e.g. `Page 2 of 4` instead.
+    #[inline]
+    fn format_page_indicator(
+        &self,
+        f: &mut dyn fmt::Write,
+        page: usize,
+        pages: usize,
+    ) -> fmt::Result {
+        write!(f, "[{}/{}]", page + 1, pages)
+    }
+
     /// Formats a sort prompt.
     #[inline]
     fn format_sort_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
         self.format_prompt(f, prompt)
     }
 
+    /// Formats a key press prompt.
+    #[inline]
+    fn format_key_press_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
+        self.format_prompt(f, prompt)
+    }
+
     /// Formats a multi_select prompt after selection.
     fn format_multi_select_prompt_selection(
         &self,
@@ -154,6 +299,59 @@ pub trait Theme {
         write!(f, "{} {}", if active { ">" } else { " " }, text)
     }
 
+    /// Formats an item of an inline select prompt.
+    ///
+    /// The default puts the active item in square brackets.
+    #[inline]
+    fn format_inline_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+    ) -> fmt::Result {
+        if active {
+            write!(f, "[{}]", text)
+        } else {
+            write!(f, "{}", text)
+        }
+    }
+
+    /// Returns the marker of a checked multi select prompt item.
+    #[inline]
+    fn checkbox_checked_prefix(&self) -> &str {
+        "[x]"
+    }
+
+    /// Returns the marker of an unchecked multi select prompt item.
+    #[inline]
+    fn checkbox_unchecked_prefix(&self) -> &str {
+        "[ ]"
+    }
+
+    /// Returns the marker of an unchecked multi select prompt item that
+    /// cannot be checked.
+    #[inline]
+    fn checkbox_disabled_prefix(&self) -> &str {
+        "[-]"
+    }
+
+    /// Returns the text between the prompt and the search of a multi select
+    /// prompt.
+    #[inline]
+    fn search_separator(&self) -> &str {
+        " "
+    }
+
+    /// Returns the text shown in place of the selections when a multi select
+    /// prompt is confirmed without checking any item.
+    ///
+    /// The default is empty, which leaves out the line summarizing the
+    /// selections in that case.
+    #[inline]
+    fn multi_select_empty_selection_text(&self) -> &str {
+        ""
+    }
+
     /// Formats a multi select prompt item.
     fn format_multi_select_prompt_item(
         &self,
@@ -164,17 +362,196 @@ pub trait Theme {
     ) -> fmt::Result {
         write!(
             f,
-            "{} {}",
-            match (checked, active) {
-                (true, true) => "> [x]",
-                (true, false) => "  [x]",
-                (false, true) => "> [ ]",
-                (false, false) => "  [ ]",
+            "{} {} {}",
+            if active { ">" } else { " " },
+            if checked {
+                self.checkbox_checked_prefix()
+            } else {
+                self.checkbox_unchecked_prefix()
+            },
+            text
+        )
+    }
+
+    /// Formats the header of a group of select prompt items.
+    #[inline]
+    fn format_select_prompt_group_header(
+        &self,
+        f: &mut dyn fmt::Write,
+        label: &str,
+    ) -> fmt::Result {
+        write!(f, "{}:", label)
+    }
+
+    /// Formats the indicator for items hidden above or below the current page.
+    #[inline]
+    fn format_select_prompt_more_items(
+        &self,
+        f: &mut dyn fmt::Write,
+        above: bool,
+        count: usize,
+    ) -> fmt::Result {
+        write!(f, "  {} {} more", if above { "^" } else { "v" }, count)
+    }
+
+    /// Formats a select prompt item with a custom cursor glyph.
+    fn format_select_prompt_item_with_cursor(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+        cursor: char,
+    ) -> fmt::Result {
+        write!(f, "{} {}", if active { cursor } else { ' ' }, text)
+    }
+
+    /// Formats a fuzzy select prompt along with the search term typed so far.
+    #[inline]
+    fn format_fuzzy_select_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        search_term: &str,
+    ) -> fmt::Result {
+        self.format_input_prompt(f, prompt, None)?;
+        write!(f, "{}", search_term)
+    }
+
+    /// Formats a fuzzy select prompt item.
+    ///
+    /// `matches` holds the positions of the characters in `text` that match
+    /// the search term.
+    #[inline]
+    fn format_fuzzy_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+        matches: &[usize],
+    ) -> fmt::Result {
+        let _ = matches;
+        self.format_select_prompt_item(f, text, active)
+    }
+
+    /// Formats the header line of a table select prompt.
+    #[inline]
+    fn format_table_header(&self, f: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        write!(f, "  {}", text)
+    }
+
+    /// Formats a row of a table select prompt.
+    #[inline]
+    fn format_table_row(&self, f: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        self.format_select_prompt_item(f, text, false)
+    }
+
+    /// Formats the selected row of a table select prompt.
+    #[inline]
+    fn format_table_selected_row(&self, f: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        self.format_select_prompt_item(f, text, true)
+    }
+
+    /// Formats a multi select prompt item that cannot be checked.
+    fn format_multi_select_prompt_item_disabled(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        checked: bool,
+        active: bool,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} (disabled)",
+            if active { ">" } else { " " },
+            if checked {
+                self.checkbox_checked_prefix()
+            } else {
+                self.checkbox_disabled_prefix()
+            },
+            text
+        )
+    }
+
+    /// Formats the description shown below a multi select prompt item.
+    #[inline]
+    fn format_multi_select_prompt_item_desc(
+        &self,
+        f: &mut dyn fmt::Write,
+        desc: &str,
+    ) -> fmt::Result {
+        write!(f, "      {}", desc)
+    }
+
+    /// Formats a line of the preview shown below a multi select prompt.
+    #[inline]
+    fn format_preview_line(&self, f: &mut dyn fmt::Write, line: &str) -> fmt::Result {
+        write!(f, "  | {}", line)
+    }
+
+    /// Formats a multi select prompt item with a custom cursor glyph.
+    fn format_multi_select_prompt_item_with_cursor(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        checked: bool,
+        active: bool,
+        cursor: char,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            if active { cursor } else { ' ' },
+            if checked {
+                self.checkbox_checked_prefix()
+            } else {
+                self.checkbox_unchecked_prefix()
             },
             text
         )
     }
 
+    /// Formats a frame of a spinner.
+    #[inline]
+    fn format_spinner(&self, f: &mut dyn fmt::Write, tick: &str, message: &str) -> fmt::Result {
+        write!(f, "{} {}", tick, message)
+    }
+
+    /// Returns the characters used for the filled and the empty part of a
+    /// progress bar.
+    #[inline]
+    fn progress_chars(&self) -> (char, char) {
+        ('█', '░')
+    }
+
+    /// Formats a progress bar that is `width` characters wide.
+    ///
+    /// `pos` never exceeds `total`, a `total` of zero is shown as complete.
+    fn format_progress_bar(
+        &self,
+        f: &mut dyn fmt::Write,
+        pos: u64,
+        total: u64,
+        width: usize,
+        message: &str,
+    ) -> fmt::Result {
+        let (filled, empty) = progress_widths(pos, total, width);
+        let (filled_char, empty_char) = self.progress_chars();
+
+        write!(
+            f,
+            "{}{} {:>3}%",
+            filled_char.to_string().repeat(filled),
+            empty_char.to_string().repeat(empty),
+            progress_percent(pos, total)
+        )?;
+
+        if !message.is_empty() {
+            write!(f, " {}", message)?;
+        }
+
+        Ok(())
+    }
+
     /// Formats a sort prompt item.
     fn format_sort_prompt_item(
         &self,
@@ -194,6 +571,40 @@ pub trait Theme {
             text
         )
     }
+
+    /// Formats the title of a date picker month followed by a line of weekday names.
+    fn format_date_picker_month(
+        &self,
+        f: &mut dyn fmt::Write,
+        year: u16,
+        month: u8,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{} {}\n{}",
+            MONTH_NAMES[(month as usize + 11) % 12],
+            year,
+            WEEKDAY_HEADER
+        )
+    }
+
+    /// Formats a day in a date picker.
+    ///
+    /// Every day takes up four columns, days outside of the allowed range are
+    /// not `enabled`.
+    fn format_date_picker_day(
+        &self,
+        f: &mut dyn fmt::Write,
+        day: u8,
+        active: bool,
+        enabled: bool,
+    ) -> fmt::Result {
+        match (active, enabled) {
+            (true, _) => write!(f, "[{:>2}]", day),
+            (false, true) => write!(f, " {:>2} ", day),
+            (false, false) => write!(f, " -- "),
+        }
+    }
 }
 
 /// The default theme.
@@ -201,6 +612,86 @@ pub struct SimpleTheme;
 
 impl Theme for SimpleTheme {}
 
+lazy_static::lazy_static! {
+    static ref GLOBAL_THEME: RwLock<Option<&'static (dyn Theme + Sync)>> = RwLock::new(None);
+}
+
+thread_local! {
+    static THREAD_THEME: Cell<Option<&'static dyn Theme>> = Cell::new(None);
+}
+
+/// Sets the theme used by prompts created with `new()`.
+///
+/// Prompts created with `with_theme` are not affected. A theme set for the
+/// current thread with [set_thread_theme](fn.set_thread_theme.html) takes
+/// precedence.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{theme::{self, ColorfulTheme}, Confirm};
+///
+/// theme::set_global_theme(Box::leak(Box::new(ColorfulTheme::default())));
+///
+/// let proceed = Confirm::new().with_prompt("Continue?").interact();
+/// ```
+pub fn set_global_theme(theme: &'static (dyn Theme + Sync)) {
+    *GLOBAL_THEME.write().unwrap_or_else(|err| err.into_inner()) = Some(theme);
+}
+
+/// Sets the theme used by prompts created with `new()` on the current thread.
+///
+/// Passing `None` falls back to the global theme again.
+pub fn set_thread_theme(theme: Option<&'static dyn Theme>) {
+    THREAD_THEME.with(|cell| cell.set(theme));
+}
+
+/// Prints `msg` as a success message on stderr.
+///
+/// The message is prefixed with the
+/// [success_prefix](trait.Theme.html#method.success_prefix) of `theme`, so
+/// it matches the prompts rendered before it.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{theme::{self, ColorfulTheme}, Confirm};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let theme = ColorfulTheme::default();
+///
+/// if Confirm::with_theme(&theme).with_prompt("Deploy?").interact()? {
+///     theme::render_success("Deployed", &theme)?;
+/// } else {
+///     theme::render_error("Deployment cancelled", &theme)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_success(msg: &str, theme: &dyn Theme) -> io::Result<()> {
+    Term::stderr().write_line(&format!("{} {}", theme.success_prefix(), msg))
+}
+
+/// Prints `msg` as an error message on stderr.
+///
+/// The message is prefixed with the
+/// [error_prefix](trait.Theme.html#method.error_prefix) of `theme`.
+pub fn render_error(msg: &str, theme: &dyn Theme) -> io::Result<()> {
+    Term::stderr().write_line(&format!("{} {}", theme.error_prefix(), msg))
+}
+
+/// Returns the theme for prompts created without an explicit theme.
+pub(crate) fn default_theme() -> &'static dyn Theme {
+    if let Some(theme) = THREAD_THEME.with(Cell::get) {
+        return theme;
+    }
+
+    match *GLOBAL_THEME.read().unwrap_or_else(|err| err.into_inner()) {
+        Some(theme) => theme,
+        None => &SimpleTheme,
+    }
+}
+
 /// A colorful theme
 pub struct ColorfulTheme {
     /// The style for default values
@@ -239,6 +730,8 @@ pub struct ColorfulTheme {
     pub picked_item_prefix: StyledObject<String>,
     /// Unpicked item in sort prefix value and style
     pub unpicked_item_prefix: StyledObject<String>,
+    /// The style for characters matching the search in fuzzy select
+    pub fuzzy_match_highlight_style: Style,
     /// Show the selections from certain prompts inline
     pub inline_selections: bool,
 }
@@ -264,34 +757,269 @@ impl Default for ColorfulTheme {
             unchecked_item_prefix: style("✔".to_string()).for_stderr().black(),
             picked_item_prefix: style("❯".to_string()).for_stderr().green(),
             unpicked_item_prefix: style(" ".to_string()).for_stderr(),
+            fuzzy_match_highlight_style: Style::new().for_stderr().yellow().bold(),
             inline_selections: true,
         }
     }
 }
 
-impl Theme for ColorfulTheme {
-    /// Formats a prompt.
-    fn format_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
-        if !prompt.is_empty() {
-            write!(
-                f,
-                "{} {} ",
-                &self.prompt_prefix,
-                self.prompt_style.apply_to(prompt)
-            )?;
+impl ColorfulTheme {
+    /// Creates the default colorful theme with `accent` as its primary color.
+    ///
+    /// The accent is used for active items, defaults, accepted values and the
+    /// item prefixes, while the prompt, hints and errors keep their colors.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::console::Style;
+    /// use dialoguer::theme::ColorfulTheme;
+    ///
+    /// let theme = ColorfulTheme::with_accent(Style::new().for_stderr().magenta());
+    /// ```
+    pub fn with_accent(accent: Style) -> ColorfulTheme {
+        ColorfulTheme {
+            defaults_style: accent.clone(),
+            values_style: accent.clone(),
+            active_item_style: accent.clone(),
+            active_item_prefix: accent.apply_to("❯".to_string()),
+            checked_item_prefix: accent.apply_to("✔".to_string()),
+            picked_item_prefix: accent.apply_to("❯".to_string()),
+            ..ColorfulTheme::default()
         }
-
-        write!(f, "{}", &self.prompt_suffix)
     }
 
-    /// Formats an error
-    fn format_error(&self, f: &mut dyn fmt::Write, err: &str) -> fmt::Result {
-        write!(
-            f,
-            "{} {}",
-            &self.error_prefix,
-            self.error_style.apply_to(err)
-        )
+    /// Creates a colorful theme from a config loaded at runtime.
+    ///
+    /// Everything the config leaves unset is taken from the default theme.
+    ///
+    /// ```rust
+    /// use dialoguer::theme::{ColorfulTheme, PrefixConfig, ThemeConfig};
+    ///
+    /// let theme = ColorfulTheme::from_config(&ThemeConfig {
+    ///     prompt_style: Some("magenta.bold".into()),
+    ///     prompt_prefix: Some(PrefixConfig {
+    ///         text: ">".into(),
+    ///         style: Some("magenta".into()),
+    ///     }),
+    ///     ..ThemeConfig::default()
+    /// });
+    /// ```
+    pub fn from_config(config: &ThemeConfig) -> ColorfulTheme {
+        let mut theme = ColorfulTheme::default();
+
+        for (style, field) in [
+            (&config.defaults_style, &mut theme.defaults_style),
+            (&config.prompt_style, &mut theme.prompt_style),
+            (&config.error_style, &mut theme.error_style),
+            (&config.hint_style, &mut theme.hint_style),
+            (&config.values_style, &mut theme.values_style),
+            (&config.active_item_style, &mut theme.active_item_style),
+            (&config.inactive_item_style, &mut theme.inactive_item_style),
+            (
+                &config.fuzzy_match_highlight_style,
+                &mut theme.fuzzy_match_highlight_style,
+            ),
+        ] {
+            if let Some(style) = style {
+                *field = config_style(style);
+            }
+        }
+
+        for (prefix, field) in [
+            (&config.prompt_prefix, &mut theme.prompt_prefix),
+            (&config.prompt_suffix, &mut theme.prompt_suffix),
+            (&config.success_prefix, &mut theme.success_prefix),
+            (&config.success_suffix, &mut theme.success_suffix),
+            (&config.error_prefix, &mut theme.error_prefix),
+            (&config.active_item_prefix, &mut theme.active_item_prefix),
+            (
+                &config.inactive_item_prefix,
+                &mut theme.inactive_item_prefix,
+            ),
+            (&config.checked_item_prefix, &mut theme.checked_item_prefix),
+            (
+                &config.unchecked_item_prefix,
+                &mut theme.unchecked_item_prefix,
+            ),
+            (&config.picked_item_prefix, &mut theme.picked_item_prefix),
+            (
+                &config.unpicked_item_prefix,
+                &mut theme.unpicked_item_prefix,
+            ),
+        ] {
+            if let Some(prefix) = prefix {
+                *field = prefix
+                    .style
+                    .as_ref()
+                    .map_or_else(|| Style::new().for_stderr(), |style| config_style(style))
+                    .apply_to(prefix.text.clone());
+            }
+        }
+
+        if let Some(inline_selections) = config.inline_selections {
+            theme.inline_selections = inline_selections;
+        }
+
+        theme
+    }
+}
+
+/// The colors and prefixes of a [ColorfulTheme](struct.ColorfulTheme.html),
+/// to be loaded at runtime.
+///
+/// Styles are written in the dotted notation of `console::Style::from_dotted_str`,
+/// for example `"cyan.bold"` or `"black.bright.on_white"`. Names `console` does
+/// not know are ignored. Unset fields keep the value of the default theme.
+///
+/// With the `serde` feature the config can be serialized and deserialized,
+/// and a `ColorfulTheme` can be deserialized from it directly. The `toml` and
+/// `json` features add a parser for that format, they enable `serde` as well:
+///
+/// ```rust,ignore
+/// use dialoguer::theme::{ColorfulTheme, ThemeConfig};
+///
+/// let config = ThemeConfig::from_toml_str(r#"
+///     prompt_style = "magenta.bold"
+///     values_style = "cyan"
+///
+///     [prompt_prefix]
+///     text = ">"
+///     style = "magenta"
+/// "#)?;
+/// let theme = ColorfulTheme::from_config(&config);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ThemeConfig {
+    /// The style for default values
+    pub defaults_style: Option<String>,
+    /// The style for prompt
+    pub prompt_style: Option<String>,
+    /// The style for error message
+    pub error_style: Option<String>,
+    /// The style for hints
+    pub hint_style: Option<String>,
+    /// The style for values on prompt success
+    pub values_style: Option<String>,
+    /// The style for active items
+    pub active_item_style: Option<String>,
+    /// The style for inactive items
+    pub inactive_item_style: Option<String>,
+    /// The style for characters matching the search in fuzzy select
+    pub fuzzy_match_highlight_style: Option<String>,
+    /// Prompt prefix
+    pub prompt_prefix: Option<PrefixConfig>,
+    /// Prompt suffix
+    pub prompt_suffix: Option<PrefixConfig>,
+    /// Prompt on success prefix
+    pub success_prefix: Option<PrefixConfig>,
+    /// Prompt on success suffix
+    pub success_suffix: Option<PrefixConfig>,
+    /// Error prefix
+    pub error_prefix: Option<PrefixConfig>,
+    /// Active item in select prefix
+    pub active_item_prefix: Option<PrefixConfig>,
+    /// Inactive item in select prefix
+    pub inactive_item_prefix: Option<PrefixConfig>,
+    /// Checked item in multi select prefix
+    pub checked_item_prefix: Option<PrefixConfig>,
+    /// Unchecked item in multi select prefix
+    pub unchecked_item_prefix: Option<PrefixConfig>,
+    /// Picked item in sort prefix
+    pub picked_item_prefix: Option<PrefixConfig>,
+    /// Unpicked item in sort prefix
+    pub unpicked_item_prefix: Option<PrefixConfig>,
+    /// Show the selections from certain prompts inline
+    pub inline_selections: Option<bool>,
+}
+
+impl ThemeConfig {
+    /// Parses a config from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(config: &str) -> io::Result<ThemeConfig> {
+        toml::from_str(config).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Parses a config from JSON.
+    #[cfg(feature = "json")]
+    pub fn from_json_str(config: &str) -> io::Result<ThemeConfig> {
+        serde_json::from_str(config).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+/// A prefix of a [ThemeConfig](struct.ThemeConfig.html), which replaces the
+/// text and the style of the default prefix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrefixConfig {
+    /// The text of the prefix
+    pub text: String,
+    /// The style of the prefix, unstyled if unset
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub style: Option<String>,
+}
+
+/// Deserializes a [ThemeConfig](struct.ThemeConfig.html) and builds the
+/// theme from it, see [from_config](struct.ColorfulTheme.html#method.from_config).
+///
+/// There is no `Serialize` counterpart, as the styles of a theme cannot be
+/// turned back into text. Serialize a `ThemeConfig` instead.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ColorfulTheme {
+    fn deserialize<D>(deserializer: D) -> Result<ColorfulTheme, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let config = <ThemeConfig as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(ColorfulTheme::from_config(&config))
+    }
+}
+
+/// Parses a style of a [ThemeConfig](struct.ThemeConfig.html).
+fn config_style(style: &str) -> Style {
+    Style::from_dotted_str(style).for_stderr()
+}
+
+impl Theme for ColorfulTheme {
+    /// Returns the style of the option a confirm prompt selects on Enter.
+    fn confirm_prompt_default_selection(&self) -> Style {
+        self.defaults_style.clone().bold()
+    }
+
+    /// Formats a prompt.
+    fn format_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
+        if !prompt.is_empty() {
+            write!(
+                f,
+                "{} {} ",
+                &self.prompt_prefix,
+                self.prompt_style.apply_to(prompt)
+            )?;
+        }
+
+        write!(f, "{}", &self.prompt_suffix)
+    }
+
+    /// Formats an error
+    fn format_error(&self, f: &mut dyn fmt::Write, err: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            &self.error_prefix,
+            self.error_style.apply_to(err)
+        )
+    }
+
+    /// Returns the prefix of messages printed with [render_success].
+    fn success_prefix(&self) -> String {
+        self.success_prefix.to_string()
+    }
+
+    /// Returns the prefix of messages printed with [render_error].
+    fn error_prefix(&self) -> String {
+        self.error_prefix.to_string()
     }
 
     /// Formats an input prompt.
@@ -327,6 +1055,28 @@ impl Theme for ColorfulTheme {
         f: &mut dyn fmt::Write,
         prompt: &str,
         default: Option<bool>,
+    ) -> fmt::Result {
+        self.format_confirm_prompt_with_labels(f, prompt, default, "yes", "no")
+    }
+
+    /// Formats a confirm prompt after selection.
+    fn format_confirm_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        selection: bool,
+    ) -> fmt::Result {
+        self.format_confirm_prompt_selection_with_labels(f, prompt, selection, "yes", "no")
+    }
+
+    /// Formats a confirm prompt answered with custom texts.
+    fn format_confirm_prompt_with_labels(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        default: Option<bool>,
+        yes: &str,
+        no: &str,
     ) -> fmt::Result {
         if !prompt.is_empty() {
             write!(
@@ -337,36 +1087,54 @@ impl Theme for ColorfulTheme {
             )?;
         }
 
+        let (yes_key, no_key) = confirm_keys(yes, no);
+        let hint = match default {
+            None => self
+                .hint_style
+                .apply_to(format!("({}/{})", yes_key, no_key))
+                .to_string(),
+            Some(default) => {
+                let key = |key: &str, selected| {
+                    if selected == default {
+                        self.confirm_prompt_default_selection()
+                            .apply_to(key)
+                            .to_string()
+                    } else {
+                        self.hint_style.apply_to(key).to_string()
+                    }
+                };
+
+                format!(
+                    "{}{}{}{}{}",
+                    self.hint_style.apply_to("("),
+                    key(&yes_key, true),
+                    self.hint_style.apply_to("/"),
+                    key(&no_key, false),
+                    self.hint_style.apply_to(")")
+                )
+            }
+        };
+
         match default {
-            None => write!(
-                f,
-                "{} {}",
-                self.hint_style.apply_to("(y/n)"),
-                &self.prompt_suffix
-            ),
-            Some(true) => write!(
-                f,
-                "{} {} {}",
-                self.hint_style.apply_to("(y/n)"),
-                &self.prompt_suffix,
-                self.defaults_style.apply_to("yes")
-            ),
-            Some(false) => write!(
+            None => write!(f, "{} {}", hint, &self.prompt_suffix),
+            Some(default) => write!(
                 f,
                 "{} {} {}",
-                self.hint_style.apply_to("(y/n)"),
+                hint,
                 &self.prompt_suffix,
-                self.defaults_style.apply_to("no")
+                self.defaults_style.apply_to(if default { yes } else { no })
             ),
         }
     }
 
-    /// Formats a confirm prompt after selection.
-    fn format_confirm_prompt_selection(
+    /// Formats a confirm prompt answered with custom texts after selection.
+    fn format_confirm_prompt_selection_with_labels(
         &self,
         f: &mut dyn fmt::Write,
         prompt: &str,
         selection: bool,
+        yes: &str,
+        no: &str,
     ) -> fmt::Result {
         if !prompt.is_empty() {
             write!(
@@ -381,8 +1149,7 @@ impl Theme for ColorfulTheme {
             f,
             "{} {}",
             &self.success_suffix,
-            self.values_style
-                .apply_to(if selection { "yes" } else { "no" })
+            self.values_style.apply_to(if selection { yes } else { no })
         )
     }
 
@@ -410,6 +1177,16 @@ impl Theme for ColorfulTheme {
         )
     }
 
+    /// Formats the text added to an input by a completion.
+    fn format_input_completion(&self, f: &mut dyn fmt::Write, completion: &str) -> fmt::Result {
+        write!(f, "{}", self.active_item_style.apply_to(completion))
+    }
+
+    /// Formats the default of an input shown as ghost text while the input is empty.
+    fn format_input_placeholder(&self, f: &mut dyn fmt::Write, placeholder: &str) -> fmt::Result {
+        write!(f, "{}", self.hint_style.apply_to(placeholder))
+    }
+
     /// Formats a password prompt after selection.
     fn format_password_prompt_selection(
         &self,
@@ -419,6 +1196,23 @@ impl Theme for ColorfulTheme {
         self.format_input_prompt_selection(f, prompt, "********")
     }
 
+    /// Formats the strength of the password entered so far.
+    fn format_password_strength(&self, f: &mut dyn fmt::Write, score: u8) -> fmt::Result {
+        let score = score.min(4) as usize;
+        let style = match score {
+            0 | 1 => self.error_style.clone(),
+            2 => Style::new().for_stderr().yellow(),
+            _ => self.values_style.clone(),
+        };
+
+        write!(
+            f,
+            "{}{}",
+            style.apply_to("■".repeat(score)),
+            self.hint_style.apply_to("■".repeat(4 - score))
+        )
+    }
+
     /// Formats a multi select prompt after selection.
     fn format_multi_select_prompt_selection(
         &self,
@@ -451,6 +1245,21 @@ impl Theme for ColorfulTheme {
         Ok(())
     }
 
+    /// Formats the page indicator of a paged prompt.
+    fn format_page_indicator(
+        &self,
+        f: &mut dyn fmt::Write,
+        page: usize,
+        pages: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.hint_style
+                .apply_to(&format!("[{}/{}]", page + 1, pages))
+        )
+    }
+
     /// Formats a select prompt item.
     fn format_select_prompt_item(
         &self,
@@ -472,6 +1281,56 @@ impl Theme for ColorfulTheme {
         write!(f, "{} {}", details.0, details.1)
     }
 
+    /// Formats an item of an inline select prompt.
+    fn format_inline_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+    ) -> fmt::Result {
+        if active {
+            write!(f, "{}", self.active_item_style.apply_to(text))
+        } else {
+            write!(f, "{}", self.inactive_item_style.apply_to(text))
+        }
+    }
+
+    /// Formats the header line of a table select prompt.
+    fn format_table_header(&self, f: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.inactive_item_prefix,
+            self.prompt_style.apply_to(text)
+        )
+    }
+
+    /// Formats a fuzzy select prompt item.
+    fn format_fuzzy_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+        matches: &[usize],
+    ) -> fmt::Result {
+        let (prefix, item_style) = match active {
+            true => (&self.active_item_prefix, &self.active_item_style),
+            false => (&self.inactive_item_prefix, &self.inactive_item_style),
+        };
+
+        write!(f, "{} ", prefix)?;
+
+        for (idx, chr) in text.chars().enumerate() {
+            if matches.contains(&idx) {
+                write!(f, "{}", self.fuzzy_match_highlight_style.apply_to(chr))?;
+            } else {
+                write!(f, "{}", item_style.apply_to(chr))?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Formats a multi select prompt item.
     fn format_multi_select_prompt_item(
         &self,
@@ -502,6 +1361,163 @@ impl Theme for ColorfulTheme {
         write!(f, "{} {}", details.0, details.1)
     }
 
+    /// Formats the header of a group of select prompt items.
+    fn format_select_prompt_group_header(
+        &self,
+        f: &mut dyn fmt::Write,
+        label: &str,
+    ) -> fmt::Result {
+        write!(f, "{}", self.prompt_style.apply_to(label))
+    }
+
+    /// Formats the indicator for items hidden above or below the current page.
+    fn format_select_prompt_more_items(
+        &self,
+        f: &mut dyn fmt::Write,
+        above: bool,
+        count: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "  {}",
+            self.hint_style
+                .apply_to(format!("{} {} more", if above { "↑" } else { "↓" }, count))
+        )
+    }
+
+    /// Formats a select prompt item with a custom cursor glyph.
+    fn format_select_prompt_item_with_cursor(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+        cursor: char,
+    ) -> fmt::Result {
+        if active {
+            write!(
+                f,
+                "{} {}",
+                self.active_item_style.apply_to(cursor),
+                self.active_item_style.apply_to(text)
+            )
+        } else {
+            write!(
+                f,
+                "{} {}",
+                &self.inactive_item_prefix,
+                self.inactive_item_style.apply_to(text)
+            )
+        }
+    }
+
+    /// Formats a multi select prompt item that cannot be checked.
+    fn format_multi_select_prompt_item_disabled(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        checked: bool,
+        active: bool,
+    ) -> fmt::Result {
+        let prefix = if checked {
+            &self.checked_item_prefix
+        } else {
+            &self.unchecked_item_prefix
+        };
+
+        if active {
+            write!(f, "{} {}", prefix, self.active_item_style.apply_to(text))
+        } else {
+            write!(f, "{} {}", prefix, self.hint_style.apply_to(text))
+        }
+    }
+
+    /// Formats the description shown below a multi select prompt item.
+    fn format_multi_select_prompt_item_desc(
+        &self,
+        f: &mut dyn fmt::Write,
+        desc: &str,
+    ) -> fmt::Result {
+        write!(f, "    {}", self.hint_style.apply_to(desc))
+    }
+
+    /// Formats a line of the preview shown below a multi select prompt.
+    fn format_preview_line(&self, f: &mut dyn fmt::Write, line: &str) -> fmt::Result {
+        write!(f, "  {} {}", self.hint_style.apply_to("│"), line)
+    }
+
+    /// Formats a multi select prompt item with a custom cursor glyph.
+    fn format_multi_select_prompt_item_with_cursor(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        checked: bool,
+        active: bool,
+        cursor: char,
+    ) -> fmt::Result {
+        let prefix = if checked {
+            &self.checked_item_prefix
+        } else {
+            &self.unchecked_item_prefix
+        };
+
+        if active {
+            write!(
+                f,
+                "{} {} {}",
+                self.active_item_style.apply_to(cursor),
+                prefix,
+                self.active_item_style.apply_to(text)
+            )
+        } else {
+            write!(
+                f,
+                "  {} {}",
+                prefix,
+                self.inactive_item_style.apply_to(text)
+            )
+        }
+    }
+
+    /// Formats a frame of a spinner.
+    fn format_spinner(&self, f: &mut dyn fmt::Write, tick: &str, message: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.active_item_style.apply_to(tick),
+            self.prompt_style.apply_to(message)
+        )
+    }
+
+    /// Formats a progress bar that is `width` characters wide.
+    fn format_progress_bar(
+        &self,
+        f: &mut dyn fmt::Write,
+        pos: u64,
+        total: u64,
+        width: usize,
+        message: &str,
+    ) -> fmt::Result {
+        let (filled, empty) = progress_widths(pos, total, width);
+        let (filled_char, empty_char) = self.progress_chars();
+
+        write!(
+            f,
+            "{}{} {}",
+            self.values_style
+                .apply_to(filled_char.to_string().repeat(filled)),
+            self.hint_style
+                .apply_to(empty_char.to_string().repeat(empty)),
+            self.hint_style
+                .apply_to(format!("{:>3}%", progress_percent(pos, total)))
+        )?;
+
+        if !message.is_empty() {
+            write!(f, " {}", self.prompt_style.apply_to(message))?;
+        }
+
+        Ok(())
+    }
+
     /// Formats a sort prompt item.
     fn format_sort_prompt_item(
         &self,
@@ -527,50 +1543,389 @@ impl Theme for ColorfulTheme {
 
         write!(f, "{} {}", details.0, details.1)
     }
+
+    /// Formats the title of a date picker month followed by a line of weekday names.
+    fn format_date_picker_month(
+        &self,
+        f: &mut dyn fmt::Write,
+        year: u16,
+        month: u8,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{}\n{}",
+            self.prompt_style.apply_to(format!(
+                "{} {}",
+                MONTH_NAMES[(month as usize + 11) % 12],
+                year
+            )),
+            self.hint_style.apply_to(WEEKDAY_HEADER)
+        )
+    }
+
+    /// Formats a day in a date picker.
+    fn format_date_picker_day(
+        &self,
+        f: &mut dyn fmt::Write,
+        day: u8,
+        active: bool,
+        enabled: bool,
+    ) -> fmt::Result {
+        match (active, enabled) {
+            (true, _) => write!(
+                f,
+                "{}",
+                self.active_item_style.apply_to(format!("[{:>2}]", day))
+            ),
+            (false, true) => write!(
+                f,
+                "{}",
+                self.inactive_item_style.apply_to(format!(" {:>2} ", day))
+            ),
+            (false, false) => write!(f, "{}", self.hint_style.apply_to(format!(" {:>2} ", day))),
+        }
+    }
+}
+
+/// Where a [TermThemeRenderer] writes its output.
+enum Output<'a> {
+    /// The terminal of the renderer.
+    Term,
+    /// A buffer, see [capture_output](TermThemeRenderer::capture_output).
+    Captured(Vec<u8>),
+    /// A writer, see [new_with_writer](TermThemeRenderer::new_with_writer).
+    Writer(RefCell<Box<dyn io::Write + 'a>>),
 }
 
-/// Helper struct to conveniently render a theme ot a term.
-pub(crate) struct TermThemeRenderer<'a> {
+/// Helper struct to conveniently render a theme to a term.
+///
+/// The renderer keeps track of the lines it has written so that custom
+/// prompts can clear and redraw themselves the same way the built-in ones do.
+pub struct TermThemeRenderer<'a> {
     term: &'a Term,
     theme: &'a dyn Theme,
     height: usize,
+    line_widths: Vec<usize>,
+    line_open: bool,
     prompt_height: usize,
     prompts_reset_height: bool,
+    cursor_char: Option<char>,
+    plain: bool,
+    output: Output<'a>,
+    frame: Vec<String>,
+    frame_size: (u16, u16),
+    prompt_frame: Vec<String>,
+    previous_frame: Vec<String>,
+    redrawing: Cell<bool>,
 }
 
 impl<'a> TermThemeRenderer<'a> {
+    /// Creates a renderer that draws `theme` on `term`.
     pub fn new(term: &'a Term, theme: &'a dyn Theme) -> TermThemeRenderer<'a> {
         TermThemeRenderer {
             term,
             theme,
             height: 0,
+            line_widths: vec![],
+            line_open: false,
             prompt_height: 0,
             prompts_reset_height: true,
+            cursor_char: None,
+            plain: !term.features().colors_supported(),
+            output: Output::Term,
+            frame: vec![],
+            frame_size: term.size(),
+            prompt_frame: vec![],
+            previous_frame: vec![],
+            redrawing: Cell::new(false),
         }
     }
 
+    /// Creates a renderer that writes to `writer` instead of drawing on `term`.
+    ///
+    /// The size of `term` is still used to lay out the output, but nothing is
+    /// written to it. Like with [capture_output](#method.capture_output)
+    /// clearing and cursor changes are skipped. This allows inspecting the
+    /// output of a custom prompt, for example by passing a `&mut Vec<u8>`:
+    ///
+    /// ```rust
+    /// use dialoguer::{console::Term, theme::{SimpleTheme, TermThemeRenderer}};
+    ///
+    /// let mut buf = Vec::new();
+    ///
+    /// {
+    ///     let term = Term::stderr();
+    ///     let mut render = TermThemeRenderer::new_with_writer(&term, &SimpleTheme, &mut buf);
+    ///     render.select_prompt_item("Apple", true).unwrap();
+    /// }
+    ///
+    /// assert_eq!(buf, b"> Apple\n");
+    /// ```
+    pub fn new_with_writer<W: io::Write + 'a>(
+        term: &'a Term,
+        theme: &'a dyn Theme,
+        writer: W,
+    ) -> TermThemeRenderer<'a> {
+        let mut render = TermThemeRenderer::new(term, theme);
+        render.output = Output::Writer(RefCell::new(Box::new(writer)));
+        render
+    }
+
+    /// Sets whether rendering a prompt resets the tracked height.
+    ///
+    /// When enabled (the default) [clear](#method.clear) only removes what was
+    /// rendered since the last prompt line.
     pub fn set_prompts_reset_height(&mut self, val: bool) {
         self.prompts_reset_height = val;
     }
 
-    pub fn term(&self) -> &Term {
-        self.term
+    /// Overrides the cursor glyph of the theme for select items.
+    pub fn set_cursor_char(&mut self, val: Option<char>) {
+        self.cursor_char = val;
     }
 
-    pub fn add_line(&mut self) {
-        self.height += 1;
+    /// Writes everything into a buffer instead of the terminal.
+    ///
+    /// This allows running prompts without a terminal, for example in tests.
+    /// Clearing and cursor changes are skipped while capturing, and lines a
+    /// redraw leaves unchanged are not written again.
+    pub fn capture_output(&mut self) {
+        self.output = Output::Captured(vec![]);
     }
 
-    fn write_formatted_str<
-        F: FnOnce(&mut TermThemeRenderer, &mut dyn fmt::Write) -> fmt::Result,
-    >(
-        &mut self,
-        f: F,
-    ) -> io::Result<()> {
-        let mut buf = String::new();
-        f(self, &mut buf).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
-        self.height += buf.chars().filter(|&x| x == '\n').count();
-        self.term.write_str(&buf)
+    /// Returns the output written since [capture_output](#method.capture_output)
+    /// was called.
+    pub fn captured_output(&self) -> &[u8] {
+        match self.output {
+            Output::Captured(ref captured) => captured,
+            _ => &[],
+        }
+    }
+
+    /// Returns whether the output goes to the terminal.
+    fn on_term(&self) -> bool {
+        matches!(self.output, Output::Term)
+    }
+
+    /// Returns whether redraws can skip unchanged lines, which requires
+    /// moving the cursor on the terminal.
+    fn can_redraw(&self) -> bool {
+        match self.output {
+            Output::Term => !self.plain,
+            Output::Captured(_) => true,
+            Output::Writer(_) => false,
+        }
+    }
+
+    /// Hides the cursor of the terminal.
+    pub fn hide_cursor(&self) -> io::Result<()> {
+        match self.output {
+            Output::Term => self.term.hide_cursor(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Shows the cursor of the terminal.
+    pub fn show_cursor(&self) -> io::Result<()> {
+        match self.output {
+            Output::Term => self.term.show_cursor(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Flushes the output of the terminal.
+    ///
+    /// This completes a [redraw](#method.redraw_preserve_prompt), erasing the
+    /// lines of the previous frame that were not rendered again.
+    pub fn flush(&self) -> io::Result<()> {
+        match self.output {
+            Output::Term => {
+                self.finish_redraw()?;
+                self.term.flush()
+            }
+            Output::Captured(_) => self.finish_redraw(),
+            Output::Writer(ref writer) => writer.borrow_mut().flush(),
+        }
+    }
+
+    /// Returns the terminal the renderer draws on.
+    pub fn term(&self) -> &'a Term {
+        self.term
+    }
+
+    /// Records a line written to the terminal outside of the renderer.
+    pub fn add_line(&mut self) {
+        self.height += 1;
+    }
+
+    /// Returns the number of terminal lines rendered since the last clear,
+    /// not counting the prompt.
+    ///
+    /// Lines wider than the terminal are wrapped by it and count twice.
+    pub fn lines_rendered(&self) -> usize {
+        self.height + wrapped_lines(&self.line_widths, self.term.size().1 as usize)
+    }
+
+    /// Forgets the lines rendered so far.
+    fn reset_height(&mut self) {
+        self.height = 0;
+        self.line_widths.clear();
+        self.line_open = false;
+        self.frame.clear();
+        self.frame_size = self.term.size();
+    }
+
+    /// Erases what is left of the previous frame below the cursor if a
+    /// redraw is in progress.
+    fn finish_redraw(&self) -> io::Result<()> {
+        if self.redrawing.replace(false) && self.on_term() {
+            self.term.clear_to_end_of_screen()?;
+        }
+        Ok(())
+    }
+
+    /// While redrawing, moves the cursor past `text` if the previous frame
+    /// rendered the same line at this position and returns `true`.
+    ///
+    /// Otherwise clears the old line so `text` can be written in its place.
+    /// Once lines no longer match up row by row, the rest of the previous
+    /// frame is erased and the redraw falls back to writing everything.
+    fn skip_unchanged(&mut self, text: &str, newline: bool) -> io::Result<bool> {
+        if !self.redrawing.get() {
+            return Ok(false);
+        }
+
+        let width = self.term.size().1 as usize;
+        let on_term = self.on_term();
+
+        match self
+            .previous_frame
+            .get(self.prompt_frame.len() + self.frame.len())
+        {
+            Some(prev) if newline && !self.line_open && prev == text => {
+                if on_term {
+                    self.term.move_cursor_down(line_rows(text, width))?;
+                }
+                Ok(true)
+            }
+            Some(prev)
+                if newline
+                    && !self.line_open
+                    && line_rows(prev, width) == 1
+                    && line_rows(text, width) == 1 =>
+            {
+                if on_term {
+                    self.term.clear_line()?;
+                }
+                Ok(false)
+            }
+            _ => {
+                self.finish_redraw()?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Records the display widths of the lines in `text`, which continues
+    /// the last line unless that one was terminated.
+    ///
+    /// Text after a carriage return is rendered over the start of the line,
+    /// so it replaces the width of the open line.
+    fn track_widths(&mut self, text: &str, newline: bool) {
+        let mut lines = text.split('\n');
+
+        if let Some(first) = lines.next() {
+            let (first, rewound) = match first.rfind('\r') {
+                Some(pos) => (&first[pos + 1..], true),
+                None => (first, false),
+            };
+            let width = measure_text_width(first);
+
+            match self.line_widths.last_mut() {
+                Some(last) if self.line_open && rewound => *last = width,
+                Some(last) if self.line_open => *last += width,
+                _ => self.line_widths.push(width),
+            }
+        }
+
+        for line in lines {
+            self.line_widths.push(measure_text_width(line));
+        }
+
+        self.line_open = !newline;
+    }
+
+    /// Applies the prompt style of the theme to `prompt`.
+    ///
+    /// Empty prompts stay empty, as themes check for them to leave out
+    /// the prompt entirely.
+    fn styled_prompt(&self, prompt: &str) -> String {
+        if prompt.is_empty() {
+            String::new()
+        } else {
+            self.theme.prompt_style().apply_to(prompt).to_string()
+        }
+    }
+
+    /// Strips escape codes from `buf` if the terminal does not support them.
+    fn plain_text<'b>(&self, buf: &'b str) -> std::borrow::Cow<'b, str> {
+        if self.plain {
+            strip_ansi_codes(buf)
+        } else {
+            buf.into()
+        }
+    }
+
+    /// Writes `text` to the terminal or the capture buffer.
+    fn write(&mut self, text: &str, newline: bool) -> io::Result<()> {
+        let text = self.plain_text(text).into_owned();
+        let skip = self.skip_unchanged(&text, newline)?;
+
+        if newline && !self.line_open {
+            self.frame.push(text.clone());
+        }
+
+        self.track_widths(&text, newline);
+
+        if skip {
+            return Ok(());
+        }
+
+        match self.output {
+            Output::Term if newline => self.term.write_line(&text),
+            Output::Term => self.term.write_str(&text),
+            Output::Captured(ref mut captured) => {
+                captured.extend_from_slice(text.as_bytes());
+
+                if newline {
+                    captured.push(b'\n');
+                }
+
+                Ok(())
+            }
+            Output::Writer(ref writer) => {
+                let mut writer = writer.borrow_mut();
+                writer.write_all(text.as_bytes())?;
+
+                if newline {
+                    writer.write_all(b"\n")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    fn write_formatted_str<
+        F: FnOnce(&mut TermThemeRenderer, &mut dyn fmt::Write) -> fmt::Result,
+    >(
+        &mut self,
+        f: F,
+    ) -> io::Result<()> {
+        let mut buf = String::new();
+        f(self, &mut buf).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        self.height += buf.chars().filter(|&x| x == '\n').count();
+        self.write(&buf, false)
     }
 
     fn write_formatted_line<
@@ -582,7 +1937,18 @@ impl<'a> TermThemeRenderer<'a> {
         let mut buf = String::new();
         f(self, &mut buf).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
         self.height += buf.chars().filter(|&x| x == '\n').count() + 1;
-        self.term.write_line(&buf)
+        self.write(&buf, true)
+    }
+
+    /// Writes `items` side by side, separated by `delimiter` and wrapped at
+    /// the width of the terminal.
+    fn write_horizontal(&mut self, items: &[String], delimiter: &str) -> io::Result<()> {
+        for row in layout_rows(items, delimiter, self.term.size().1 as usize) {
+            self.height += 1;
+            self.write(&row, true)?;
+        }
+
+        Ok(())
     }
 
     fn write_formatted_prompt<
@@ -593,36 +1959,88 @@ impl<'a> TermThemeRenderer<'a> {
     ) -> io::Result<()> {
         self.write_formatted_line(f)?;
         if self.prompts_reset_height {
-            self.prompt_height = self.height;
-            self.height = 0;
+            // Counts prompt lines wrapped by the terminal, so clearing the
+            // prompt removes all of it.
+            self.prompt_height = self.lines_rendered();
+            self.prompt_frame = mem::take(&mut self.frame);
+            self.reset_height();
         }
         Ok(())
     }
 
+    /// Renders an error line.
     pub fn error(&mut self, err: &str) -> io::Result<()> {
         self.write_formatted_line(|this, buf| this.theme.format_error(buf, err))
     }
 
+    /// Renders the message of a failed input validation.
+    pub fn validation_error(&mut self, err: &str) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| this.theme.format_validation_error(buf, err))
+    }
+
+    /// Renders a confirm prompt.
     pub fn confirm_prompt(&mut self, prompt: &str, default: Option<bool>) -> io::Result<()> {
-        self.write_formatted_str(|this, buf| this.theme.format_confirm_prompt(buf, prompt, default))
+        let prompt = self.styled_prompt(prompt);
+        self.write_formatted_str(|this, buf| {
+            this.theme.format_confirm_prompt(buf, &prompt, default)
+        })
     }
 
+    /// Renders a confirm prompt after selection.
     pub fn confirm_prompt_selection(&mut self, prompt: &str, sel: bool) -> io::Result<()> {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_confirm_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders a confirm prompt answered with custom texts.
+    pub fn confirm_prompt_with_labels(
+        &mut self,
+        prompt: &str,
+        default: Option<bool>,
+        yes: &str,
+        no: &str,
+    ) -> io::Result<()> {
+        let prompt = self.styled_prompt(prompt);
+        self.write_formatted_str(|this, buf| {
+            this.theme
+                .format_confirm_prompt_with_labels(buf, &prompt, default, yes, no)
+        })
+    }
+
+    /// Renders a confirm prompt answered with custom texts after selection.
+    pub fn confirm_prompt_selection_with_labels(
+        &mut self,
+        prompt: &str,
+        sel: bool,
+        yes: &str,
+        no: &str,
+    ) -> io::Result<()> {
+        self.write_formatted_prompt(|this, buf| {
+            this.theme
+                .format_confirm_prompt_selection_with_labels(buf, prompt, sel, yes, no)
+        })
+    }
+
+    /// Renders an input prompt.
     pub fn input_prompt(&mut self, prompt: &str, default: Option<&str>) -> io::Result<()> {
-        self.write_formatted_str(|this, buf| this.theme.format_input_prompt(buf, prompt, default))
+        let prompt = self.styled_prompt(prompt);
+        self.write_formatted_str(|this, buf| this.theme.format_input_prompt(buf, &prompt, default))
     }
 
+    /// Renders an input prompt after selection.
     pub fn input_prompt_selection(&mut self, prompt: &str, sel: &str) -> io::Result<()> {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_input_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders the text added to an input by a completion.
+    pub fn input_completion(&mut self, completion: &str) -> io::Result<()> {
+        self.write_formatted_str(|this, buf| this.theme.format_input_completion(buf, completion))
+    }
+
+    /// Renders a password prompt.
     pub fn password_prompt(&mut self, prompt: &str) -> io::Result<()> {
         self.write_formatted_str(|this, buf| {
             write!(buf, "\r")?;
@@ -630,61 +2048,309 @@ impl<'a> TermThemeRenderer<'a> {
         })
     }
 
+    /// Renders a password prompt after selection.
     pub fn password_prompt_selection(&mut self, prompt: &str) -> io::Result<()> {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_password_prompt_selection(buf, prompt)
         })
     }
 
+    /// Renders the strength of a password.
+    pub fn password_strength(&mut self, score: u8) -> io::Result<()> {
+        self.write_formatted_str(|this, buf| this.theme.format_password_strength(buf, score))
+    }
+
+    /// Renders a select prompt.
     pub fn select_prompt(&mut self, prompt: &str) -> io::Result<()> {
-        self.write_formatted_prompt(|this, buf| this.theme.format_select_prompt(buf, prompt))
+        let prompt = self.styled_prompt(prompt);
+        self.write_formatted_prompt(|this, buf| this.theme.format_select_prompt(buf, &prompt))
     }
 
+    /// Renders a select prompt after selection.
     pub fn select_prompt_selection(&mut self, prompt: &str, sel: &str) -> io::Result<()> {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_select_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders a select prompt item.
     pub fn select_prompt_item(&mut self, text: &str, active: bool) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| match this.cursor_char {
+            Some(cursor) => this
+                .theme
+                .format_select_prompt_item_with_cursor(buf, text, active, cursor),
+            None => this.theme.format_select_prompt_item(buf, text, active),
+        })
+    }
+
+    /// Renders a select prompt followed by its items on a single line.
+    ///
+    /// `items` holds the text of every item and whether it is active.
+    pub fn select_prompt_inline(&mut self, prompt: &str, items: &[(&str, bool)]) -> io::Result<()> {
+        let prompt = self.styled_prompt(prompt);
         self.write_formatted_line(|this, buf| {
-            this.theme.format_select_prompt_item(buf, text, active)
+            if !prompt.is_empty() {
+                this.theme.format_select_prompt(buf, &prompt)?;
+                write!(buf, " ")?;
+            }
+
+            for (idx, &(text, active)) in items.iter().enumerate() {
+                if idx > 0 {
+                    write!(buf, " / ")?;
+                }
+
+                this.theme
+                    .format_inline_select_prompt_item(buf, text, active)?;
+            }
+
+            Ok(())
         })
     }
 
-    pub fn multi_select_prompt(&mut self, prompt: &str) -> io::Result<()> {
-        self.write_formatted_prompt(|this, buf| this.theme.format_multi_select_prompt(buf, prompt))
+    /// Renders select prompt items side by side.
+    ///
+    /// `items` holds the text of every item and whether it is active. Returns
+    /// the display width of every rendered row.
+    pub fn select_prompt_items_horizontal(
+        &mut self,
+        items: &[(&str, bool)],
+        delimiter: &str,
+    ) -> io::Result<()> {
+        let mut formatted = Vec::with_capacity(items.len());
+
+        for &(text, active) in items {
+            let mut buf = String::new();
+            match self.cursor_char {
+                Some(cursor) => self
+                    .theme
+                    .format_select_prompt_item_with_cursor(&mut buf, text, active, cursor),
+                None => self.theme.format_select_prompt_item(&mut buf, text, active),
+            }
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+            formatted.push(buf);
+        }
+
+        self.write_horizontal(&formatted, delimiter)
     }
 
+    /// Renders the header of a group of select prompt items.
+    pub fn select_prompt_group_header(&mut self, label: &str) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| {
+            this.theme.format_select_prompt_group_header(buf, label)
+        })
+    }
+
+    /// Renders the indicator for `count` items hidden above or below the current page.
+    pub fn select_prompt_more_items(&mut self, above: bool, count: usize) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| {
+            this.theme
+                .format_select_prompt_more_items(buf, above, count)
+        })
+    }
+
+    /// Renders a fuzzy select prompt along with the search term.
+    pub fn fuzzy_select_prompt(&mut self, prompt: &str, search_term: &str) -> io::Result<()> {
+        self.write_formatted_prompt(|this, buf| {
+            this.theme
+                .format_fuzzy_select_prompt(buf, prompt, search_term)
+        })
+    }
+
+    /// Renders a fuzzy select prompt item with the matching characters highlighted.
+    pub fn fuzzy_select_prompt_item(
+        &mut self,
+        text: &str,
+        active: bool,
+        matches: &[usize],
+    ) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| {
+            this.theme
+                .format_fuzzy_select_prompt_item(buf, text, active, matches)
+        })
+    }
+
+    /// Renders the header line of a table select prompt.
+    pub fn table_header(&mut self, text: &str) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| this.theme.format_table_header(buf, text))
+    }
+
+    /// Renders a row of a table select prompt.
+    pub fn table_row(&mut self, text: &str) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| this.theme.format_table_row(buf, text))
+    }
+
+    /// Renders the selected row of a table select prompt.
+    pub fn table_selected_row(&mut self, text: &str) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| this.theme.format_table_selected_row(buf, text))
+    }
+
+    /// Renders all but the last line of a prompt spanning several lines.
+    ///
+    /// Returns the last line, which is formatted by the theme like a single
+    /// line prompt.
+    fn prompt_preamble<'p>(&mut self, prompt: &'p str) -> io::Result<&'p str> {
+        let (preamble, last) = match prompt.rfind('\n') {
+            Some(idx) => (&prompt[..idx], &prompt[idx + 1..]),
+            None => return Ok(prompt),
+        };
+
+        for line in preamble.split('\n') {
+            let line = self.styled_prompt(line);
+            self.write_formatted_line(|_, buf| write!(buf, "{}", line))?;
+        }
+
+        Ok(last)
+    }
+
+    /// Renders a multi select prompt, optionally with a `(page, pages)` indicator.
+    ///
+    /// Newlines in `prompt` start a new line, the theme formats the last one.
+    pub fn multi_select_prompt(
+        &mut self,
+        prompt: &str,
+        paging: Option<(usize, usize)>,
+    ) -> io::Result<()> {
+        let prompt = self.prompt_preamble(prompt)?;
+        let prompt = self.styled_prompt(prompt);
+        self.write_formatted_prompt(|this, buf| {
+            this.theme.format_multi_select_prompt(buf, &prompt)?;
+
+            if let Some((page, pages)) = paging {
+                write!(buf, " ")?;
+                this.theme.format_page_indicator(buf, page, pages)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Renders a multi select prompt after selection.
+    ///
+    /// Without selections the theme's
+    /// [empty selection text](Theme::multi_select_empty_selection_text) is
+    /// shown instead, if it has one.
     pub fn multi_select_prompt_selection(&mut self, prompt: &str, sel: &[&str]) -> io::Result<()> {
+        let empty = [self.theme.multi_select_empty_selection_text()];
+        let sel = match sel {
+            [] if empty[0].is_empty() => return Ok(()),
+            [] => &empty[..],
+            sel => sel,
+        };
+
+        let prompt = self.prompt_preamble(prompt)?;
+
         self.write_formatted_prompt(|this, buf| {
             this.theme
                 .format_multi_select_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders a multi select prompt item.
     pub fn multi_select_prompt_item(
         &mut self,
         text: &str,
         checked: bool,
         active: bool,
+    ) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| match this.cursor_char {
+            Some(cursor) => this
+                .theme
+                .format_multi_select_prompt_item_with_cursor(buf, text, checked, active, cursor),
+            None => this
+                .theme
+                .format_multi_select_prompt_item(buf, text, checked, active),
+        })
+    }
+
+    /// Renders multi select prompt items side by side.
+    ///
+    /// `items` holds the text of every item and whether it is checked, active
+    /// and disabled. Returns the display width of every rendered row.
+    pub fn multi_select_prompt_items_horizontal(
+        &mut self,
+        items: &[(&str, bool, bool, bool)],
+        delimiter: &str,
+    ) -> io::Result<()> {
+        let mut formatted = Vec::with_capacity(items.len());
+
+        for &(text, checked, active, disabled) in items {
+            let mut buf = String::new();
+            match self.cursor_char {
+                _ if disabled => self
+                    .theme
+                    .format_multi_select_prompt_item_disabled(&mut buf, text, checked, active),
+                Some(cursor) => self.theme.format_multi_select_prompt_item_with_cursor(
+                    &mut buf, text, checked, active, cursor,
+                ),
+                None => self
+                    .theme
+                    .format_multi_select_prompt_item(&mut buf, text, checked, active),
+            }
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+            formatted.push(buf);
+        }
+
+        self.write_horizontal(&formatted, delimiter)
+    }
+
+    /// Renders a multi select prompt item that cannot be checked.
+    pub fn multi_select_prompt_item_disabled(
+        &mut self,
+        text: &str,
+        checked: bool,
+        active: bool,
     ) -> io::Result<()> {
         self.write_formatted_line(|this, buf| {
             this.theme
-                .format_multi_select_prompt_item(buf, text, checked, active)
+                .format_multi_select_prompt_item_disabled(buf, text, checked, active)
         })
     }
 
+    /// Renders the description of a multi select prompt item.
+    pub fn multi_select_prompt_item_desc(&mut self, desc: &str) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| {
+            this.theme.format_multi_select_prompt_item_desc(buf, desc)
+        })
+    }
+
+    /// Renders the lines of a preview, cut off at the width of the terminal.
+    pub fn preview(&mut self, lines: &[String]) -> io::Result<()> {
+        let width = self.term.size().1 as usize;
+
+        for line in lines {
+            self.write_formatted_line(|this, buf| {
+                let mut text = String::new();
+                this.theme.format_preview_line(&mut text, line)?;
+                write!(buf, "{}", truncate_str(&text, width, ""))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders an error below a multi select prompt.
+    pub fn multi_select_prompt_error(&mut self, err: &str) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| this.theme.format_error(buf, err))
+    }
+
+    /// Renders a sort prompt.
     pub fn sort_prompt(&mut self, prompt: &str) -> io::Result<()> {
         self.write_formatted_prompt(|this, buf| this.theme.format_sort_prompt(buf, prompt))
     }
 
+    /// Renders a key press prompt.
+    pub fn key_press_prompt(&mut self, prompt: &str) -> io::Result<()> {
+        self.write_formatted_prompt(|this, buf| this.theme.format_key_press_prompt(buf, prompt))
+    }
+
+    /// Renders a sort prompt after selection.
     pub fn sort_prompt_selection(&mut self, prompt: &str, sel: &[&str]) -> io::Result<()> {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_sort_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders a sort prompt item.
     pub fn sort_prompt_item(&mut self, text: &str, picked: bool, active: bool) -> io::Result<()> {
         self.write_formatted_line(|this, buf| {
             this.theme
@@ -692,23 +2358,518 @@ impl<'a> TermThemeRenderer<'a> {
         })
     }
 
+    /// Renders the title of a date picker month.
+    pub fn date_picker_month(&mut self, year: u16, month: u8) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| this.theme.format_date_picker_month(buf, year, month))
+    }
+
+    /// Renders a week of a date picker.
+    ///
+    /// `days` holds a `(day, active, enabled)` triple for every weekday
+    /// starting on monday, or `None` for days outside of the month.
+    pub fn date_picker_week(&mut self, days: &[Option<(u8, bool, bool)>]) -> io::Result<()> {
+        self.write_formatted_line(|this, buf| {
+            for day in days {
+                match *day {
+                    Some((day, active, enabled)) => this
+                        .theme
+                        .format_date_picker_day(buf, day, active, enabled)?,
+                    None => write!(buf, "    ")?,
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Clears everything rendered so far, including the prompt.
+    ///
+    /// Terminals without ANSI support cannot move the cursor, so nothing is
+    /// removed there and the next render is printed below the previous one.
     pub fn clear(&mut self) -> io::Result<()> {
-        self.term
-            .clear_last_lines(self.height + self.prompt_height)?;
-        self.height = 0;
+        if !self.plain && self.on_term() {
+            self.finish_redraw()?;
+            self.term
+                .clear_last_lines(self.lines_rendered() + self.prompt_height)?;
+        }
+        self.prompt_frame.clear();
+        self.reset_height();
         Ok(())
     }
 
-    pub fn clear_preserve_prompt(&mut self, size_vec: &[usize]) -> io::Result<()> {
-        let mut new_height = self.height;
-        //Check each item size, increment on finding an overflow
-        for size in size_vec {
-            if *size > self.term.size().1 as usize {
-                new_height += 1;
-            }
+    /// Clears the rendered lines but keeps the prompt.
+    ///
+    /// Lines wrapped by the terminal are cleared as well, see
+    /// [lines_rendered](#method.lines_rendered).
+    pub fn clear_preserve_prompt(&mut self) -> io::Result<()> {
+        if !self.plain && self.on_term() {
+            self.finish_redraw()?;
+            self.term.clear_last_lines(self.lines_rendered())?;
         }
-        self.term.clear_last_lines(new_height)?;
-        self.height = 0;
+        self.reset_height();
         Ok(())
     }
+
+    /// Prepares redrawing the rendered lines but keeps the prompt.
+    ///
+    /// Unlike [clear_preserve_prompt](#method.clear_preserve_prompt) this
+    /// only moves the cursor back to the first rendered line. The lines
+    /// rendered next are compared with the ones on screen and identical lines
+    /// are skipped instead of being erased and written again, which avoids
+    /// flicker on slow terminals. The redraw is completed by
+    /// [flush](#method.flush).
+    ///
+    /// If the terminal was resized since the lines were rendered they are
+    /// cleared instead, as the terminal may have wrapped them differently.
+    pub fn redraw_preserve_prompt(&mut self) -> io::Result<()> {
+        if !self.can_redraw() || self.line_open || self.term.size() != self.frame_size {
+            return self.clear_preserve_prompt();
+        }
+
+        self.finish_redraw()?;
+
+        let lines = self.lines_rendered();
+        if lines > 0 && self.on_term() {
+            self.term.move_cursor_up(lines)?;
+        }
+
+        let mut previous = self.prompt_frame.clone();
+        previous.append(&mut self.frame);
+        self.previous_frame = previous;
+        self.redrawing.set(true);
+        self.reset_height();
+        Ok(())
+    }
+
+    /// Prepares redrawing everything rendered so far, including the prompt.
+    ///
+    /// This is the counterpart of [clear](#method.clear) to
+    /// [redraw_preserve_prompt](#method.redraw_preserve_prompt), for prompts
+    /// that render their prompt on every frame, for example to show a search.
+    /// The prompt is skipped like the other lines if it did not change.
+    pub fn redraw(&mut self) -> io::Result<()> {
+        if !self.can_redraw() || self.line_open || self.term.size() != self.frame_size {
+            return self.clear();
+        }
+
+        self.finish_redraw()?;
+
+        let lines = self.lines_rendered() + self.prompt_height;
+        if lines > 0 && self.on_term() {
+            self.term.move_cursor_up(lines)?;
+        }
+
+        let mut previous = mem::take(&mut self.prompt_frame);
+        previous.append(&mut self.frame);
+        self.previous_frame = previous;
+        self.redrawing.set(true);
+        self.reset_height();
+        Ok(())
+    }
+}
+
+/// Returns the keys answering a confirm prompt with the texts `yes` and `no`.
+fn confirm_keys(yes: &str, no: &str) -> (String, String) {
+    let key = |text: &str| text.chars().next().map(|c| c.to_lowercase().collect());
+
+    (key(yes).unwrap_or_default(), key(no).unwrap_or_default())
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAY_HEADER: &str = " Mo  Tu  We  Th  Fr  Sa  Su ";
+
+/// Joins `items` into rows separated by `delimiter`, starting a new row
+/// whenever the next item would exceed `width`.
+fn layout_rows(items: &[String], delimiter: &str, width: usize) -> Vec<String> {
+    let delimiter_width = measure_text_width(delimiter);
+    let mut rows = vec![];
+    let mut row = String::new();
+    let mut row_width = 0;
+
+    for item in items {
+        let item_width = measure_text_width(item);
+
+        if row_width > 0 && row_width + delimiter_width + item_width > width {
+            rows.push(mem::take(&mut row));
+            row_width = 0;
+        }
+
+        if row_width > 0 {
+            row.push_str(delimiter);
+            row_width += delimiter_width;
+        }
+
+        row.push_str(item);
+        row_width += item_width;
+    }
+
+    if !row.is_empty() {
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Returns the percentage of `total` that `pos` makes up.
+fn progress_percent(pos: u64, total: u64) -> u64 {
+    if total == 0 {
+        100
+    } else {
+        (pos.min(total) as u128 * 100 / total as u128) as u64
+    }
+}
+
+/// Splits `width` into the filled and the empty part of a progress bar.
+fn progress_widths(pos: u64, total: u64, width: usize) -> (usize, usize) {
+    let filled = if total == 0 {
+        width
+    } else {
+        (pos.min(total) as u128 * width as u128 / total as u128) as usize
+    };
+
+    (filled, width - filled)
+}
+
+/// Returns the number of extra lines used by items wider than the terminal.
+///
+/// `size_vec` holds display widths as returned by `console::measure_text_width`,
+/// so color codes in the items do not count towards an overflow.
+fn wrapped_lines(size_vec: &[usize], width: usize) -> usize {
+    size_vec.iter().filter(|&&size| size > width).count()
+}
+
+/// Returns the number of terminal rows `text` takes up on a terminal `width`
+/// columns wide, counting wrapped lines like [wrapped_lines].
+fn line_rows(text: &str, width: usize) -> usize {
+    let widths: Vec<_> = text.split('\n').map(measure_text_width).collect();
+    widths.len() + wrapped_lines(&widths, width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_rows() {
+        assert_eq!(line_rows("Vanilla Cupcake", 20), 1);
+        assert_eq!(line_rows("Vanilla Cupcake", 10), 2);
+        assert_eq!(line_rows("Vanilla\nCupcake", 10), 2);
+        assert_eq!(line_rows("\x1b[31mVanilla Cupcake\x1b[0m", 15), 1);
+    }
+
+    #[test]
+    fn test_wrapped_lines_colored_item() {
+        let item = "\x1b[31mVanilla Cupcake\x1b[0m";
+
+        assert_eq!(measure_text_width(item), 15);
+        assert_eq!(wrapped_lines(&[measure_text_width(item)], 20), 0);
+        assert_eq!(wrapped_lines(&[measure_text_width(item)], 10), 1);
+    }
+
+    #[test]
+    fn test_wrapped_lines_multi_byte_item() {
+        // Each character is two columns wide but takes up three bytes
+        let item = "ケーキ";
+        let width = measure_text_width(item);
+
+        assert_eq!(width, 6);
+        assert_eq!(item.len(), 9);
+
+        // Exactly as wide as the terminal, and one column wider
+        assert_eq!(wrapped_lines(&[width], 6), 0);
+        assert_eq!(wrapped_lines(&[width], 5), 1);
+        assert_eq!(line_rows(item, 6), 1);
+        assert_eq!(line_rows(item, 5), 2);
+    }
+
+    #[test]
+    fn test_lines_rendered() {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, &SimpleTheme);
+        render.capture_output();
+
+        render.select_prompt("Pick").unwrap();
+        render.select_prompt_item("Vanilla Cupcake", true).unwrap();
+        render
+            .select_prompt_item("Chocolate Muffin", false)
+            .unwrap();
+        assert_eq!(render.lines_rendered(), 2);
+
+        let width = term.size().1 as usize;
+        render
+            .select_prompt_item(&"A".repeat(width + 1), false)
+            .unwrap();
+        assert_eq!(render.lines_rendered(), 4);
+
+        render.clear_preserve_prompt().unwrap();
+        assert_eq!(render.lines_rendered(), 0);
+    }
+
+    #[test]
+    fn test_password_prompt_rerender() {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, &SimpleTheme);
+        render.capture_output();
+
+        render.password_prompt("Password").unwrap();
+
+        // Rendered like a password with a strength meter on every key press
+        for score in 0..20 {
+            render.password_strength(score).unwrap();
+            render.password_prompt("Password").unwrap();
+        }
+
+        // Entering the password ends the line
+        render.add_line();
+        assert_eq!(render.lines_rendered(), 1);
+    }
+
+    #[test]
+    fn test_multi_line_prompt() {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, &SimpleTheme);
+        render.capture_output();
+
+        render
+            .multi_select_prompt("Read this first\nPick", None)
+            .unwrap();
+        assert_eq!(render.prompt_height, 2);
+        assert_eq!(render.lines_rendered(), 0);
+
+        let width = term.size().1 as usize;
+        render
+            .multi_select_prompt(&format!("{}\nPick", "A".repeat(width + 1)), None)
+            .unwrap();
+        assert_eq!(render.prompt_height, 3);
+    }
+
+    #[test]
+    fn test_thread_theme_precedence() {
+        // Not zero-sized, so its address differs from the other themes
+        struct TestTheme(#[allow(dead_code)] u8);
+        impl Theme for TestTheme {}
+        static THEME: TestTheme = TestTheme(0);
+
+        std::thread::spawn(|| {
+            let theme = &THEME as *const TestTheme as *const ();
+
+            set_thread_theme(Some(&THEME));
+            assert_eq!(default_theme() as *const dyn Theme as *const (), theme);
+
+            set_thread_theme(None);
+            assert_ne!(default_theme() as *const dyn Theme as *const (), theme);
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn test_page_indicator() {
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, &SimpleTheme);
+        render.capture_output();
+
+        render.multi_select_prompt("Pick", Some((1, 4))).unwrap();
+        assert_eq!(render.captured_output(), b"Pick: [2/4]\n");
+    }
+
+    #[test]
+    fn test_empty_selection_text() {
+        struct NoneTheme;
+        impl Theme for NoneTheme {
+            fn multi_select_empty_selection_text(&self) -> &str {
+                "nothing selected"
+            }
+        }
+
+        let term = Term::stderr();
+        let mut render = TermThemeRenderer::new(&term, &NoneTheme);
+        render.capture_output();
+
+        render.multi_select_prompt_selection("Pick", &[]).unwrap();
+        render
+            .multi_select_prompt_selection("Pick", &["a"])
+            .unwrap();
+        assert_eq!(
+            render.captured_output(),
+            &b"Pick: nothing selected\nPick: a\n"[..]
+        );
+
+        let mut render = TermThemeRenderer::new(&term, &SimpleTheme);
+        render.capture_output();
+
+        render.multi_select_prompt_selection("Pick", &[]).unwrap();
+        assert_eq!(render.captured_output(), b"");
+    }
+
+    #[test]
+    fn test_password_strength() {
+        let mut buf = String::new();
+        SimpleTheme.format_password_strength(&mut buf, 1).unwrap();
+        assert_eq!(buf, "[#   ]");
+
+        buf.clear();
+        SimpleTheme.format_password_strength(&mut buf, 9).unwrap();
+        assert_eq!(buf, "[####]");
+    }
+
+    #[test]
+    fn test_theme_from_config() {
+        let theme = ColorfulTheme::from_config(&ThemeConfig {
+            values_style: Some("magenta.bold".into()),
+            success_prefix: Some(PrefixConfig {
+                text: "ok".into(),
+                style: None,
+            }),
+            inline_selections: Some(false),
+            ..ThemeConfig::default()
+        });
+
+        assert_eq!(strip_ansi_codes(&theme.success_prefix.to_string()), "ok");
+        assert_eq!(strip_ansi_codes(&theme.error_prefix.to_string()), "✘");
+        assert!(!theme.inline_selections);
+    }
+
+    #[test]
+    fn test_input_placeholder() {
+        let mut buf = String::new();
+        SimpleTheme
+            .format_input_placeholder(&mut buf, "Ferris")
+            .unwrap();
+        assert_eq!(strip_ansi_codes(&buf), "Ferris");
+    }
+
+    #[test]
+    fn test_progress_bar() {
+        let mut buf = String::new();
+        SimpleTheme
+            .format_progress_bar(&mut buf, 1, 4, 8, "Copying")
+            .unwrap();
+        assert_eq!(buf, "██░░░░░░  25% Copying");
+
+        buf.clear();
+        SimpleTheme
+            .format_progress_bar(&mut buf, 9, 0, 4, "")
+            .unwrap();
+        assert_eq!(buf, "████ 100%");
+    }
+
+    #[test]
+    fn test_styled_prompt() {
+        struct BoldTheme;
+        impl Theme for BoldTheme {
+            fn prompt_style(&self) -> Style {
+                Style::new().bold().force_styling(true)
+            }
+        }
+
+        let term = Term::stderr();
+        let render = TermThemeRenderer::new(&term, &BoldTheme);
+
+        assert_eq!(render.styled_prompt(""), "");
+        assert_eq!(
+            render.styled_prompt("Pick"),
+            BoldTheme.prompt_style().apply_to("Pick").to_string()
+        );
+    }
+
+    #[test]
+    fn test_confirm_prompt_with_labels() {
+        let mut buf = String::new();
+        SimpleTheme
+            .format_confirm_prompt_with_labels(&mut buf, "Fortfahren?", Some(true), "ja", "nein")
+            .unwrap();
+        assert_eq!(buf, "Fortfahren? [J/n] ");
+
+        let mut buf = String::new();
+        SimpleTheme
+            .format_confirm_prompt_with_labels(&mut buf, "Continue?", Some(false), "yes", "no")
+            .unwrap();
+        assert_eq!(buf, "Continue? [y/N] ");
+    }
+
+    #[test]
+    fn test_confirm_prompt_default_selection() {
+        struct BoldTheme;
+        impl Theme for BoldTheme {
+            fn confirm_prompt_default_selection(&self) -> Style {
+                Style::new().bold().force_styling(true)
+            }
+        }
+
+        let highlight = BoldTheme.confirm_prompt_default_selection();
+
+        let mut buf = String::new();
+        BoldTheme
+            .format_confirm_prompt(&mut buf, "Continue?", Some(false))
+            .unwrap();
+        assert_eq!(buf, format!("Continue? [y/{}] ", highlight.apply_to("N")));
+
+        let mut buf = String::new();
+        BoldTheme
+            .format_confirm_prompt(&mut buf, "Continue?", None)
+            .unwrap();
+        assert_eq!(buf, "Continue? [y/n] ");
+    }
+
+    #[test]
+    fn test_checkbox_prefixes() {
+        struct TickTheme;
+        impl Theme for TickTheme {
+            fn checkbox_checked_prefix(&self) -> &str {
+                "✔"
+            }
+
+            fn checkbox_unchecked_prefix(&self) -> &str {
+                "·"
+            }
+
+            fn checkbox_disabled_prefix(&self) -> &str {
+                "-"
+            }
+        }
+
+        let mut buf = String::new();
+        SimpleTheme
+            .format_multi_select_prompt_item(&mut buf, "Apple", true, true)
+            .unwrap();
+        assert_eq!(buf, "> [x] Apple");
+
+        let mut buf = String::new();
+        TickTheme
+            .format_multi_select_prompt_item(&mut buf, "Apple", false, false)
+            .unwrap();
+        assert_eq!(buf, "  · Apple");
+
+        let mut buf = String::new();
+        TickTheme
+            .format_multi_select_prompt_item_disabled(&mut buf, "Apple", false, false)
+            .unwrap();
+        assert_eq!(buf, "  - Apple (disabled)");
+    }
+
+    #[test]
+    fn test_layout_rows() {
+        let items: Vec<String> = vec!["> a".into(), "  bb".into(), "  ccc".into()];
+
+        assert_eq!(layout_rows(&items, " | ", 80), vec!["> a |   bb |   ccc"]);
+        assert_eq!(layout_rows(&items, " | ", 12), vec!["> a |   bb", "  ccc"]);
+        // Items wider than the terminal get a row of their own
+        assert_eq!(layout_rows(&items, " | ", 2), vec!["> a", "  bb", "  ccc"]);
+        assert!(layout_rows(&[], " | ", 80).is_empty());
+    }
 }